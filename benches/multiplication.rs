@@ -0,0 +1,55 @@
+#![allow(clippy::needless_return)]
+
+//! Compares the naive triple loop with the blocked kernel, run with `cargo bench`
+//! or `cargo bench --features parallel` to include the threaded path.
+//! Pass a size to change the default of 500, e.g. `cargo bench -- 1000`
+
+use std::time::{Duration, Instant};
+
+use lemonmath::matrices::Matrix;
+use lemonmath::multiplication::MultiplyConfig;
+
+fn naive(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+    let mut result = Matrix::zeros(a.rows(), b.cols());
+    for row in 0..a.rows() {
+        for x in 0..a.cols() {
+            let value = a[(row, x)];
+            for col in 0..b.cols() {
+                result[(row, col)] += value * b[(x, col)];
+            }
+        }
+    }
+    return result;
+}
+
+// The fastest of a few runs
+fn time<F: FnMut() -> Matrix<f64>>(mut f: F) -> (Duration, Matrix<f64>) {
+    let mut best = Duration::MAX;
+    let mut result = f();
+    for _ in 0..3 {
+        let start = Instant::now();
+        result = f();
+        best = best.min(start.elapsed());
+    }
+    return (best, result);
+}
+
+fn main() {
+    let size = std::env::args().skip(1).find_map(|x| x.parse().ok()).unwrap_or(500);
+    let a = Matrix::from_fn(size, size, |row, col| ((row * 7 + col * 3) % 11) as f64 - 5.0);
+    let b = Matrix::from_fn(size, size, |row, col| ((row * 5 + col * 2) % 13) as f64 / 4.0);
+
+    let (naive_time, expected) = time(|| naive(&a, &b));
+    println!("{}x{} naive:    {:?}", size, size, naive_time);
+
+    let serial = MultiplyConfig { threads: 1, ..MultiplyConfig::default() };
+    let (serial_time, result) = time(|| a.multiply(&b, &serial).unwrap());
+    assert_eq!(result, expected);
+    println!("{}x{} blocked:  {:?}", size, size, serial_time);
+
+    if cfg!(feature = "parallel") {
+        let (parallel_time, result) = time(|| a.multiply(&b, &MultiplyConfig::default()).unwrap());
+        assert_eq!(result, expected);
+        println!("{}x{} parallel: {:?}", size, size, parallel_time);
+    }
+}
@@ -0,0 +1,653 @@
+/*!
+# Decompositions
+Matrix factorizations, which can be stored and reused to solve many systems with the same matrix
+
+# Examples
+```rust
+use lemonmath::matrices::Matrix;
+use lemonmath::vectors::Vector;
+use lemonmath::fraction::Fraction;
+use lemonmath::decompositions::least_squares;
+
+// LU decomposition with partial pivoting, PA = LU
+let a = Matrix::new(2, 2, vec![2.0, 1.0, 4.0, 3.0]);
+let lu = a.lu().unwrap();
+
+assert_eq!(lu.p() * a.clone(), lu.l() * lu.u());
+assert_eq!(lu.determinant(), 2.0);
+
+// The factorization is reused for every solve
+assert_eq!(lu.solve(&Vector::new(vec![3.0, 7.0], true)), Ok(Vector::new(vec![1.0, 1.0], true)));
+assert_eq!(lu.solve(&Vector::new(vec![1.0, 2.0], true)), Ok(Vector::new(vec![0.5, 0.0], true)));
+
+// Exact results with fractions
+let a = Matrix::new(2, 2, vec![Fraction::new(1, 2), Fraction::new(1, 3), Fraction::new(1, 4), Fraction::new(1, 5)]);
+
+assert_eq!(a.lu().unwrap().determinant(), Fraction::new(1, 60));
+
+// Cholesky decomposition of a symmetric positive-definite matrix, A = LLᵀ
+let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 5.0]);
+let cholesky = a.cholesky().unwrap();
+
+assert_eq!(cholesky.l(), &Matrix::new(2, 2, vec![2.0, 0.0, 1.0, 2.0]));
+assert_eq!(cholesky.determinant(), 16.0);
+
+// QR decomposition, A = QR
+let a = Matrix::new(3, 2, vec![1.0, 1.0, 1.0, 2.0, 1.0, 3.0]);
+let qr = a.householder_qr();
+
+assert!(qr.r()[(2, 0)] == 0.0 && qr.r()[(2, 1)] == 0.0);
+
+// Least-squares fitting of a line through (1, 1), (2, 2) and (3, 2)
+let b = Vector::new(vec![1.0, 2.0, 2.0], true);
+let (x, residual) = least_squares(&a, &b).unwrap();
+
+assert!((x[0] - 2.0 / 3.0).abs() < 1e-12 && (x[1] - 0.5).abs() < 1e-12);
+assert!((residual - (1.0f64 / 6.0).sqrt()).abs() < 1e-12);
+```
+*/
+
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+use crate::matrices::{Matrix, MatrixError};
+use crate::vectors::Vector;
+use crate::fraction::Fraction;
+
+/// The number types with exact division, so elimination never truncates
+/// 
+/// Integer division rounds, so [`Matrix::lu`] is only available for floats and fractions,
+/// and integer matrices have to be turned into fractions first
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::matrices::Matrix;
+/// use lemonmath::fraction::Fraction;
+/// use lemonmath::helper::VecToFraction;
+/// 
+/// let a = Matrix::new(2, 2, vec![2, 1, 3, 4].to_fraction());
+/// 
+/// assert_eq!(a.lu().unwrap().determinant(), Fraction::new(5, 1));
+/// ```
+pub trait Field {}
+
+impl Field for f32 {}
+impl Field for f64 {}
+impl Field for Fraction {}
+
+/// An LU decomposition with partial pivoting, where `PA = LU`
+/// 
+/// `L` is unit lower triangular and `U` is upper triangular, both stored in a single matrix
+#[derive(Clone, PartialEq, Debug)]
+pub struct LU<T> {
+    lu: Matrix<T>,
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl<T> Matrix<T>
+where
+    T: Field + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Neg<Output = T> + AddAssign + PartialOrd + Default + From<u8> + Copy,
+{
+    /// LU decomposition with partial pivoting
+    /// 
+    /// Singular matrices can still be factorized, but solving with them fails
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let lu = a.lu().unwrap();
+    /// 
+    /// assert_eq!(lu.permutation(), &[1, 0]);
+    /// assert_eq!(lu.l(), Matrix::new(2, 2, vec![1.0, 0.0, 1.0 / 3.0, 1.0]));
+    /// assert_eq!(lu.u(), Matrix::new(2, 2, vec![3.0, 4.0, 0.0, 2.0 - 4.0 / 3.0]));
+    /// ```
+    pub fn lu(&self) -> Result<LU<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..size).collect();
+        let mut swaps = 0;
+        for k in 0..size {
+            let mut pivot = k;
+            for row in k + 1..size {
+                if abs(lu[(row, k)]) > abs(lu[(pivot, k)]) {
+                    pivot = row;
+                }
+            }
+            if lu[(pivot, k)] == T::default() {
+                continue;
+            }
+            if pivot != k {
+                lu.swap_rows(pivot, k);
+                permutation.swap(pivot, k);
+                swaps += 1;
+            }
+            for row in k + 1..size {
+                let factor = lu[(row, k)] / lu[(k, k)];
+                lu[(row, k)] = factor;
+                for col in k + 1..size {
+                    lu[(row, col)] = lu[(row, col)] - factor * lu[(k, col)];
+                }
+            }
+        }
+        return Ok(LU {
+            lu,
+            permutation,
+            swaps
+        });
+    }
+}
+
+impl<T> LU<T>
+where
+    T: Field + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Neg<Output = T> + AddAssign + PartialOrd + Default + From<u8> + Copy,
+{
+    /// The row of the original matrix that ended up in each row of `PA`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![0.0, 1.0, 1.0, 0.0]);
+    /// 
+    /// assert_eq!(a.lu().unwrap().permutation(), &[1, 0]);
+    /// ```
+    pub fn permutation(&self) -> &[usize] {
+        return &self.permutation;
+    }
+    /// The permutation matrix `P`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![0.0, 1.0, 1.0, 0.0]);
+    /// 
+    /// assert_eq!(a.lu().unwrap().p(), Matrix::new(2, 2, vec![0.0, 1.0, 1.0, 0.0]));
+    /// ```
+    pub fn p(&self) -> Matrix<T> {
+        let mut result = Matrix::zeros(self.lu.rows(), self.lu.cols());
+        for (row, original) in self.permutation.iter().enumerate() {
+            result[(row, *original)] = T::from(1u8);
+        }
+        return result;
+    }
+    /// The unit lower triangular matrix `L`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![2.0, 1.0, 4.0, 5.0]);
+    /// 
+    /// assert_eq!(a.lu().unwrap().l(), Matrix::new(2, 2, vec![1.0, 0.0, 0.5, 1.0]));
+    /// ```
+    pub fn l(&self) -> Matrix<T> {
+        let mut result = Matrix::identity(self.lu.rows());
+        for row in 0..self.lu.rows() {
+            for col in 0..row {
+                result[(row, col)] = self.lu[(row, col)];
+            }
+        }
+        return result;
+    }
+    /// The upper triangular matrix `U`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![2.0, 1.0, 4.0, 5.0]);
+    /// 
+    /// assert_eq!(a.lu().unwrap().u(), Matrix::new(2, 2, vec![4.0, 5.0, 0.0, -1.5]));
+    /// ```
+    pub fn u(&self) -> Matrix<T> {
+        let mut result = Matrix::zeros(self.lu.rows(), self.lu.cols());
+        for row in 0..self.lu.rows() {
+            for col in row..self.lu.cols() {
+                result[(row, col)] = self.lu[(row, col)];
+            }
+        }
+        return result;
+    }
+    /// Check if the factorized matrix is singular
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// assert!(Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).lu().unwrap().is_singular());
+    /// ```
+    pub fn is_singular(&self) -> bool {
+        return (0..self.lu.rows()).any(|x| self.lu[(x, x)] == T::default());
+    }
+    /// The determinant of the factorized matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0]);
+    /// 
+    /// assert_eq!(a.lu().unwrap().determinant(), 6.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        let mut result = T::from(1u8);
+        for x in 0..self.lu.rows() {
+            result = result * self.lu[(x, x)];
+        }
+        if self.swaps % 2 == 1 {
+            return -result;
+        }
+        return result;
+    }
+    /// Solve `Ax = b` for the factorized matrix `A`
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if `b` has the wrong length
+    /// and [`MatrixError::Singular`] if the matrix is singular
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// use lemonmath::fraction::Fraction;
+    /// use lemonmath::helper::VecToFraction;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![2, 1, 1, 3].to_fraction());
+    /// let b = Vector::new(vec![1, 2].to_fraction(), true);
+    /// 
+    /// assert_eq!(a.lu().unwrap().solve(&b), Ok(Vector::new(vec![Fraction::new(1, 5), Fraction::new(3, 5)], true)));
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, MatrixError> {
+        let size = self.lu.rows();
+        if b.len() != size {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        if self.is_singular() {
+            return Err(MatrixError::Singular);
+        }
+        let mut x: Vector<T> = self.permutation.iter().map(|row| b[*row]).collect();
+        for row in 0..size {
+            for col in 0..row {
+                x[row] = x[row] - self.lu[(row, col)] * x[col];
+            }
+        }
+        for row in (0..size).rev() {
+            for col in row + 1..size {
+                x[row] = x[row] - self.lu[(row, col)] * x[col];
+            }
+            x[row] = x[row] / self.lu[(row, row)];
+        }
+        return Ok(x);
+    }
+    /// The inverse of the factorized matrix
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::Singular`] if the matrix is singular
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![2.0, 1.0, 4.0, 3.0]);
+    /// 
+    /// assert_eq!(a.lu().unwrap().inverse(), Ok(Matrix::new(2, 2, vec![1.5, -0.5, -2.0, 1.0])));
+    /// ```
+    pub fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        let size = self.lu.rows();
+        let mut result = Matrix::zeros(size, size);
+        for col in 0..size {
+            let mut unit = Vector::new(vec![T::default(); size], true);
+            unit[col] = T::from(1u8);
+            let x = self.solve(&unit)?;
+            for row in 0..size {
+                result[(row, col)] = x[row];
+            }
+        }
+        return Ok(result);
+    }
+}
+
+/// A Cholesky decomposition of a symmetric positive-definite matrix, where `A = LLᵀ`
+#[derive(Clone, PartialEq, Debug)]
+pub struct Cholesky {
+    l: Matrix<f64>,
+}
+
+impl Matrix<f64> {
+    /// Cholesky decomposition of a symmetric positive-definite matrix
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`], [`MatrixError::NotSymmetric`] or
+    /// [`MatrixError::NotPositiveDefinite`] if the matrix isn't symmetric positive-definite
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::{Matrix, MatrixError};
+    /// 
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 1.0]);
+    /// 
+    /// assert_eq!(a.cholesky(), Err(MatrixError::NotPositiveDefinite));
+    /// ```
+    pub fn cholesky(&self) -> Result<Cholesky, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        for row in 0..size {
+            for col in 0..row {
+                if self[(row, col)] != self[(col, row)] {
+                    return Err(MatrixError::NotSymmetric);
+                }
+            }
+        }
+        let mut l = Matrix::zeros(size, size);
+        for col in 0..size {
+            let mut diagonal = self[(col, col)];
+            for k in 0..col {
+                diagonal -= l[(col, k)] * l[(col, k)];
+            }
+            if diagonal <= 0.0 || diagonal.is_nan() {
+                return Err(MatrixError::NotPositiveDefinite);
+            }
+            l[(col, col)] = diagonal.sqrt();
+            for row in col + 1..size {
+                let mut value = self[(row, col)];
+                for k in 0..col {
+                    value -= l[(row, k)] * l[(col, k)];
+                }
+                l[(row, col)] = value / l[(col, col)];
+            }
+        }
+        return Ok(Cholesky { l });
+    }
+}
+
+impl Cholesky {
+    /// The lower triangular matrix `L`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![9.0, 3.0, 3.0, 5.0]);
+    /// 
+    /// assert_eq!(a.cholesky().unwrap().l(), &Matrix::new(2, 2, vec![3.0, 0.0, 1.0, 2.0]));
+    /// ```
+    pub fn l(&self) -> &Matrix<f64> {
+        return &self.l;
+    }
+    /// The determinant of the factorized matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![9.0, 3.0, 3.0, 5.0]);
+    /// 
+    /// assert_eq!(a.cholesky().unwrap().determinant(), 36.0);
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        let mut result = 1.0;
+        for x in 0..self.l.rows() {
+            result *= self.l[(x, x)];
+        }
+        return result * result;
+    }
+    /// Solve `Ax = b` for the factorized matrix `A`
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if `b` has the wrong length
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![9.0, 3.0, 3.0, 5.0]);
+    /// let b = Vector::new(vec![12.0, 8.0], true);
+    /// 
+    /// assert_eq!(a.cholesky().unwrap().solve(&b), Ok(Vector::new(vec![1.0, 1.0], true)));
+    /// ```
+    pub fn solve(&self, b: &Vector<f64>) -> Result<Vector<f64>, MatrixError> {
+        let size = self.l.rows();
+        if b.len() != size {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let mut x = Vector::new(b.to_vec(), true);
+        for row in 0..size {
+            for col in 0..row {
+                x[row] -= self.l[(row, col)] * x[col];
+            }
+            x[row] /= self.l[(row, row)];
+        }
+        for row in (0..size).rev() {
+            for col in row + 1..size {
+                x[row] -= self.l[(col, row)] * x[col];
+            }
+            x[row] /= self.l[(row, row)];
+        }
+        return Ok(x);
+    }
+}
+
+impl Matrix<f64> {
+    /// QR decomposition using Householder reflections
+    /// 
+    /// `Q` is a square orthogonal matrix and `R` has the same dimensions as the original matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![3.0, 1.0, 4.0, 2.0]);
+    /// let qr = a.householder_qr();
+    /// let product = qr.q().clone() * qr.r().clone();
+    /// 
+    /// assert!((product - a).content().iter().all(|x| x.abs() < 1e-12));
+    /// assert_eq!(qr.r()[(1, 0)], 0.0);
+    /// ```
+    pub fn householder_qr(&self) -> QR {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut q = Matrix::identity(rows);
+        let mut r = self.clone();
+        for k in 0..cols.min(rows.saturating_sub(1)) {
+            let mut v: Vec<f64> = (k..rows).map(|row| r[(row, k)]).collect();
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+            let alpha = if v[0] > 0.0 { -norm } else { norm };
+            v[0] -= alpha;
+            let v_norm = v.iter().map(|x| x * x).sum::<f64>();
+            if v_norm == 0.0 {
+                continue;
+            }
+            for col in k..cols {
+                let dot: f64 = (k..rows).map(|row| v[row - k] * r[(row, col)]).sum();
+                for row in k..rows {
+                    r[(row, col)] -= 2.0 * v[row - k] * dot / v_norm;
+                }
+            }
+            for row in 0..rows {
+                let dot: f64 = (k..rows).map(|col| q[(row, col)] * v[col - k]).sum();
+                for col in k..rows {
+                    q[(row, col)] -= 2.0 * dot * v[col - k] / v_norm;
+                }
+            }
+            r[(k, k)] = alpha;
+            for row in k + 1..rows {
+                r[(row, k)] = 0.0;
+            }
+        }
+        return QR { q, r };
+    }
+    /// QR decomposition using Givens rotations
+    /// 
+    /// Rotations only touch two rows at a time, which suits matrices that already have many zeros below the diagonal
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(3, 2, vec![1.0, 2.0, 0.0, 1.0, 0.0, 3.0]);
+    /// let qr = a.givens_qr();
+    /// let product = qr.q().clone() * qr.r().clone();
+    /// 
+    /// assert!((product - a).content().iter().all(|x| x.abs() < 1e-12));
+    /// assert_eq!(qr.r()[(2, 1)], 0.0);
+    /// ```
+    pub fn givens_qr(&self) -> QR {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut q = Matrix::identity(rows);
+        let mut r = self.clone();
+        for col in 0..cols {
+            for row in col + 1..rows {
+                let (a, b) = (r[(col, col)], r[(row, col)]);
+                if b == 0.0 {
+                    continue;
+                }
+                let hypot = a.hypot(b);
+                let (c, s) = (a / hypot, b / hypot);
+                for x in 0..cols {
+                    let (top, bottom) = (r[(col, x)], r[(row, x)]);
+                    r[(col, x)] = c * top + s * bottom;
+                    r[(row, x)] = c * bottom - s * top;
+                }
+                for x in 0..rows {
+                    let (left, right) = (q[(x, col)], q[(x, row)]);
+                    q[(x, col)] = c * left + s * right;
+                    q[(x, row)] = c * right - s * left;
+                }
+                r[(row, col)] = 0.0;
+            }
+        }
+        return QR { q, r };
+    }
+}
+
+/// A QR decomposition, where `A = QR` with `Q` orthogonal and `R` upper triangular
+#[derive(Clone, PartialEq, Debug)]
+pub struct QR {
+    q: Matrix<f64>,
+    r: Matrix<f64>,
+}
+
+impl QR {
+    /// The orthogonal matrix `Q`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let qr = Matrix::new(2, 1, vec![3.0, 4.0]).householder_qr();
+    /// 
+    /// assert_eq!(qr.q().rows(), 2);
+    /// assert_eq!(qr.q().cols(), 2);
+    /// ```
+    pub fn q(&self) -> &Matrix<f64> {
+        return &self.q;
+    }
+    /// The upper triangular matrix `R`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let qr = Matrix::new(2, 1, vec![3.0, 4.0]).householder_qr();
+    /// 
+    /// assert_eq!(qr.r(), &Matrix::new(2, 1, vec![-5.0, 0.0]));
+    /// ```
+    pub fn r(&self) -> &Matrix<f64> {
+        return &self.r;
+    }
+    /// Find the `x` that minimizes `|Ax - b|` for the factorized matrix `A`
+    /// 
+    /// Returns the minimizing vector and the norm of the residual `Ax - b`
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if `b` has the wrong length
+    /// and [`MatrixError::RankDeficient`] if the columns of `A` are linearly dependent
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let qr = Matrix::new(2, 1, vec![1.0, 1.0]).givens_qr();
+    /// let (x, residual) = qr.least_squares(&Vector::new(vec![1.0, 3.0], true)).unwrap();
+    /// 
+    /// assert!((x[0] - 2.0).abs() < 1e-12);
+    /// assert!((residual - 2.0f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn least_squares(&self, b: &Vector<f64>) -> Result<(Vector<f64>, f64), MatrixError> {
+        let (rows, cols) = (self.r.rows(), self.r.cols());
+        if b.len() != rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        if rows < cols {
+            return Err(MatrixError::RankDeficient);
+        }
+        let largest = (0..cols).map(|x| self.r[(x, x)].abs()).fold(0.0, f64::max);
+        let tolerance = largest * f64::EPSILON * rows.max(cols) as f64;
+        if cols > 0 && (0..cols).any(|x| self.r[(x, x)].abs() <= tolerance) {
+            return Err(MatrixError::RankDeficient);
+        }
+        let mut y = vec![0.0; rows];
+        for (row, value) in y.iter_mut().enumerate() {
+            for x in 0..rows {
+                *value += self.q[(x, row)] * b[x];
+            }
+        }
+        let mut x = Vector::new(y[..cols].to_vec(), true);
+        for row in (0..cols).rev() {
+            for col in row + 1..cols {
+                x[row] -= self.r[(row, col)] * x[col];
+            }
+            x[row] /= self.r[(row, row)];
+        }
+        let residual = y[cols..].iter().map(|x| x * x).sum::<f64>().sqrt();
+        return Ok((x, residual));
+    }
+}
+
+/// Find the `x` that minimizes `|Ax - b|`, using a Householder QR decomposition of `A`
+/// 
+/// Returns the minimizing vector and the norm of the residual `Ax - b`
+/// 
+/// # Errors
+/// Returns [`MatrixError::DimensionMismatch`] if `b` has the wrong length
+/// and [`MatrixError::RankDeficient`] if the columns of `A` are linearly dependent
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::decompositions::least_squares;
+/// use lemonmath::matrices::Matrix;
+/// use lemonmath::vectors::Vector;
+/// 
+/// // Fit y = c0 + c1 * t through (0, 1), (1, 3) and (2, 5)
+/// let a = Matrix::new(3, 2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0]);
+/// let b = Vector::new(vec![1.0, 3.0, 5.0], true);
+/// let (x, residual) = least_squares(&a, &b).unwrap();
+/// 
+/// assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12);
+/// assert!(residual < 1e-12);
+/// ```
+pub fn least_squares(a: &Matrix<f64>, b: &Vector<f64>) -> Result<(Vector<f64>, f64), MatrixError> {
+    return a.householder_qr().least_squares(b);
+}
+
+fn abs<T: Neg<Output = T> + PartialOrd + Default>(value: T) -> T {
+    if value < T::default() {
+        return -value;
+    }
+    return value;
+}
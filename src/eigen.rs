@@ -0,0 +1,434 @@
+/*!
+# Eigen
+Eigenvalues and eigenvectors of real matrices
+
+# Examples
+```rust
+use lemonmath::matrices::Matrix;
+use lemonmath::eigen::{Complex, EigenConfig};
+
+// Symmetric matrices have real eigenvalues and orthogonal eigenvectors
+let a = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 2.0]);
+let eigen = a.symmetric_eigen(EigenConfig::default()).unwrap();
+
+assert!((eigen.values[0] - 1.0).abs() < 1e-12);
+assert!((eigen.values[1] - 3.0).abs() < 1e-12);
+assert!(eigen.vectors[0].dot(&eigen.vectors[1]).abs() < 1e-12);
+
+// Other matrices can have complex eigenvalues
+let rotation = Matrix::new(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+let values = rotation.eigenvalues(EigenConfig::default()).unwrap();
+
+assert!((values[0].im + 1.0).abs() < 1e-12 && values[0].re.abs() < 1e-12);
+assert!((values[1].im - 1.0).abs() < 1e-12 && values[1].re.abs() < 1e-12);
+```
+*/
+
+use std::fmt::Display;
+
+use crate::matrices::{Matrix, MatrixError};
+use crate::vectors::Vector;
+
+#[test]
+fn eigen_test() {
+    let a = Matrix::new(5, 5, vec![
+        4.0, -2.0, 1.0, 3.0, 0.5,
+        1.0, 3.0, -1.0, 2.0, 1.0,
+        0.0, 2.0, 5.0, -3.0, 2.0,
+        -1.0, 1.0, 2.0, 1.0, -4.0,
+        2.0, 0.0, -2.0, 1.0, 3.0,
+    ]);
+    let values = a.eigenvalues(EigenConfig::default()).unwrap();
+    let trace: f64 = (0..5).map(|x| a[(x, x)]).sum();
+    let (mut re, mut im) = (1.0, 0.0);
+    for x in values.iter() {
+        (re, im) = (re * x.re - im * x.im, re * x.im + im * x.re);
+    }
+    assert!((values.iter().map(|x| x.re).sum::<f64>() - trace).abs() < 1e-9);
+    assert!((re - a.lu().unwrap().determinant()).abs() < 1e-8);
+    assert!(im.abs() < 1e-8);
+
+    // A cyclic permutation has the roots of unity as eigenvalues, and needs the exceptional shifts
+    let cycle = Matrix::from_fn(7, 7, |row, col| if (row + 1) % 7 == col { 1.0 } else { 0.0 });
+    let values = cycle.eigenvalues(EigenConfig::default()).unwrap();
+    assert!(values.iter().all(|x| (x.re * x.re + x.im * x.im - 1.0).abs() < 1e-12));
+    assert!((values[6].re - 1.0).abs() < 1e-12);
+}
+
+/// A complex number, used for the eigenvalues of matrices that aren't symmetric
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    /// Create a complex number from its real and imaginary parts
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::eigen::Complex;
+    /// 
+    /// let x = Complex::new(1.0, -2.0);
+    /// 
+    /// assert_eq!(x.re, 1.0);
+    /// assert_eq!(x.im, -2.0);
+    /// ```
+    pub fn new(re: f64, im: f64) -> Self {
+        return Complex {
+            re,
+            im
+        };
+    }
+    /// Check if the imaginary part is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::eigen::Complex;
+    /// 
+    /// assert!(Complex::new(1.0, 0.0).is_real());
+    /// assert!(!Complex::new(1.0, 2.0).is_real());
+    /// ```
+    pub fn is_real(&self) -> bool {
+        return self.im == 0.0;
+    }
+}
+
+impl Display for Complex {
+    /// Display the complex number
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::eigen::Complex;
+    /// 
+    /// assert_eq!(format!("{}", Complex::new(1.0, 0.0)), "1");
+    /// assert_eq!(format!("{}", Complex::new(1.0, -2.5)), "1-2.5i");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_real() {
+            return write!(f, "{}", self.re);
+        }
+        if self.im < 0.0 {
+            return write!(f, "{}-{}i", self.re, -self.im);
+        }
+        return write!(f, "{}+{}i", self.re, self.im);
+    }
+}
+
+/// Convergence settings for the iterative eigenvalue algorithms
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EigenConfig {
+    /// How small an off-diagonal element has to be, relative to its neighbours, to count as zero
+    pub tolerance: f64,
+    /// The most sweeps (Jacobi) or QR steps per eigenvalue (Hessenberg QR) before giving up
+    pub max_iterations: usize,
+}
+
+impl Default for EigenConfig {
+    /// A tolerance of machine epsilon and up to 100 iterations
+    fn default() -> Self {
+        return EigenConfig {
+            tolerance: f64::EPSILON,
+            max_iterations: 100
+        };
+    }
+}
+
+/// The eigenvalues and eigenvectors of a symmetric matrix
+#[derive(Clone, PartialEq, Debug)]
+pub struct SymmetricEigen {
+    /// The eigenvalues in ascending order
+    pub values: Vector<f64>,
+    /// Unit column eigenvectors, in the same order as the eigenvalues
+    pub vectors: Vec<Vector<f64>>,
+}
+
+impl Matrix<f64> {
+    /// Eigenvalues and eigenvectors of a symmetric matrix, using the cyclic Jacobi method
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] or [`MatrixError::NotSymmetric`] if the matrix isn't symmetric
+    /// and [`MatrixError::NoConvergence`] if it doesn't converge within `config.max_iterations` sweeps
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// use lemonmath::eigen::EigenConfig;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![4.0, 1.0, 0.0, 1.0, 4.0, 1.0, 0.0, 1.0, 4.0]);
+    /// let eigen = a.symmetric_eigen(EigenConfig::default()).unwrap();
+    /// 
+    /// for (value, vector) in eigen.values.iter().zip(eigen.vectors.iter()) {
+    ///     let left = a.clone() * vector.clone();
+    ///     assert!(left.iter().zip(vector.iter()).all(|(x, y)| (x - value * y).abs() < 1e-12));
+    /// }
+    /// ```
+    pub fn symmetric_eigen(&self, config: EigenConfig) -> Result<SymmetricEigen, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        for row in 0..size {
+            for col in 0..row {
+                if self[(row, col)] != self[(col, row)] {
+                    return Err(MatrixError::NotSymmetric);
+                }
+            }
+        }
+        let mut a = self.clone();
+        let mut v = Matrix::identity(size);
+        let norm = a.content().iter().map(|x| x * x).sum::<f64>().sqrt();
+        let mut converged = false;
+        for _ in 0..config.max_iterations {
+            let mut off_diagonal = 0.0;
+            for row in 0..size {
+                for col in 0..size {
+                    if row != col {
+                        off_diagonal += a[(row, col)] * a[(row, col)];
+                    }
+                }
+            }
+            if off_diagonal.sqrt() <= config.tolerance * norm {
+                converged = true;
+                break;
+            }
+            for p in 0..size {
+                for q in p + 1..size {
+                    if a[(p, q)] == 0.0 {
+                        continue;
+                    }
+                    let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+                    for k in 0..size {
+                        let (kp, kq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * kp - s * kq;
+                        a[(k, q)] = s * kp + c * kq;
+                    }
+                    for k in 0..size {
+                        let (pk, qk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * pk - s * qk;
+                        a[(q, k)] = s * pk + c * qk;
+                    }
+                    a[(p, q)] = 0.0;
+                    a[(q, p)] = 0.0;
+                    for k in 0..size {
+                        let (kp, kq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * kp - s * kq;
+                        v[(k, q)] = s * kp + c * kq;
+                    }
+                }
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NoConvergence);
+        }
+        let mut order: Vec<usize> = (0..size).collect();
+        order.sort_by(|x, y| a[(*x, *x)].total_cmp(&a[(*y, *y)]));
+        return Ok(SymmetricEigen {
+            values: Vector::new(order.iter().map(|x| a[(*x, *x)]).collect(), true),
+            vectors: order.iter().map(|x| v.col(*x)).collect()
+        });
+    }
+    /// Reduce the matrix to upper Hessenberg form with Householder reflections
+    /// 
+    /// The result has the same eigenvalues and only zeros below its first subdiagonal
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0]);
+    /// let h = a.hessenberg().unwrap();
+    /// 
+    /// assert_eq!(h[(2, 0)], 0.0);
+    /// ```
+    pub fn hessenberg(&self) -> Result<Matrix<f64>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        let mut h = self.clone();
+        for k in 0..size.saturating_sub(2) {
+            let mut v: Vec<f64> = (k + 1..size).map(|row| h[(row, k)]).collect();
+            let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+            let alpha = if v[0] > 0.0 { -norm } else { norm };
+            v[0] -= alpha;
+            let v_norm = v.iter().map(|x| x * x).sum::<f64>();
+            if v_norm == 0.0 {
+                continue;
+            }
+            for col in 0..size {
+                let dot: f64 = (k + 1..size).map(|row| v[row - k - 1] * h[(row, col)]).sum();
+                for row in k + 1..size {
+                    h[(row, col)] -= 2.0 * v[row - k - 1] * dot / v_norm;
+                }
+            }
+            for row in 0..size {
+                let dot: f64 = (k + 1..size).map(|col| h[(row, col)] * v[col - k - 1]).sum();
+                for col in k + 1..size {
+                    h[(row, col)] -= 2.0 * dot * v[col - k - 1] / v_norm;
+                }
+            }
+            h[(k + 1, k)] = alpha;
+            for row in k + 2..size {
+                h[(row, k)] = 0.0;
+            }
+        }
+        return Ok(h);
+    }
+    /// Eigenvalues of a real matrix, using Hessenberg reduction and the Francis double-shift QR algorithm
+    /// 
+    /// Complex eigenvalues come in conjugate pairs. The result is sorted by real part, then imaginary part
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// and [`MatrixError::NoConvergence`] if an eigenvalue takes more than `config.max_iterations` QR steps
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::eigen::EigenConfig;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![2.0, 0.0, 0.0, 1.0, 3.0, 0.0, 4.0, 5.0, 6.0]);
+    /// let values = a.eigenvalues(EigenConfig::default()).unwrap();
+    /// 
+    /// assert!(values.iter().all(|x| x.is_real()));
+    /// assert!((values[0].re - 2.0).abs() < 1e-12);
+    /// assert!((values[1].re - 3.0).abs() < 1e-12);
+    /// assert!((values[2].re - 6.0).abs() < 1e-12);
+    /// ```
+    pub fn eigenvalues(&self, config: EigenConfig) -> Result<Vector<Complex>, MatrixError> {
+        let mut h = self.hessenberg()?;
+        let size = self.rows();
+        let norm: f64 = h.content().iter().map(|x| x.abs()).sum();
+        let mut values = Vec::with_capacity(size);
+        // The unreduced block still being iterated on is rows and columns low..high
+        let mut high = size;
+        let mut iterations = 0;
+        while high > 0 {
+            let mut low = high - 1;
+            while low > 0 {
+                let mut scale = h[(low - 1, low - 1)].abs() + h[(low, low)].abs();
+                if scale == 0.0 {
+                    scale = norm;
+                }
+                if h[(low, low - 1)].abs() <= config.tolerance * scale {
+                    h[(low, low - 1)] = 0.0;
+                    break;
+                }
+                low -= 1;
+            }
+            match high - low {
+                1 => {
+                    values.push(Complex::new(h[(low, low)], 0.0));
+                    high -= 1;
+                    iterations = 0;
+                }
+                2 => {
+                    let (first, second) = block_eigenvalues(h[(low, low)], h[(low, low + 1)], h[(low + 1, low)], h[(low + 1, low + 1)]);
+                    values.push(first);
+                    values.push(second);
+                    high -= 2;
+                    iterations = 0;
+                }
+                _ => {
+                    if iterations == config.max_iterations {
+                        return Err(MatrixError::NoConvergence);
+                    }
+                    iterations += 1;
+                    francis_step(&mut h, low, high, iterations % 10 == 0);
+                }
+            }
+        }
+        values.sort_by(|x, y| x.re.total_cmp(&y.re).then(x.im.total_cmp(&y.im)));
+        return Ok(Vector::new(values, true));
+    }
+}
+
+// The eigenvalues of the 2x2 matrix [a b; c d], with the conjugate of a complex pair first
+fn block_eigenvalues(a: f64, b: f64, c: f64, d: f64) -> (Complex, Complex) {
+    let mean = 0.5 * (a + d);
+    let half_difference = 0.5 * (a - d);
+    let discriminant = half_difference * half_difference + b * c;
+    if discriminant < 0.0 {
+        let im = (-discriminant).sqrt();
+        return (Complex::new(mean, -im), Complex::new(mean, im));
+    }
+    // Take the root that adds to the mean, and get the other from the determinant to avoid cancellation
+    let larger = mean + discriminant.sqrt().copysign(mean);
+    let smaller = if larger != 0.0 { (a * d - b * c) / larger } else { 0.0 };
+    return (Complex::new(larger, 0.0), Complex::new(smaller, 0.0));
+}
+
+// One implicit double-shift QR step on the unreduced Hessenberg block low..high, which has at least three rows.
+// The shifts are the eigenvalues of the trailing 2x2 block, entering only through their sum and product,
+// and the bulge they create below the subdiagonal is chased down with 3x3 reflections
+fn francis_step(h: &mut Matrix<f64>, low: usize, high: usize, exceptional: bool) {
+    let last = high - 1;
+    let (sum, product) = match exceptional {
+        // A made-up double shift near the bottom of the block, to break cycles the usual shifts can get stuck in
+        true => {
+            let shift = h[(last, last)] + h[(last, last - 1)].abs() + h[(last - 1, last - 2)].abs();
+            (2.0 * shift, shift * shift)
+        }
+        false => (
+            h[(last - 1, last - 1)] + h[(last, last)],
+            h[(last - 1, last - 1)] * h[(last, last)] - h[(last - 1, last)] * h[(last, last - 1)],
+        ),
+    };
+    // The first column of (H - s1)(H - s2), which only has three non-zero entries
+    let mut x = h[(low, low)] * h[(low, low)] + h[(low, low + 1)] * h[(low + 1, low)] - sum * h[(low, low)] + product;
+    let mut y = h[(low + 1, low)] * (h[(low, low)] + h[(low + 1, low + 1)] - sum);
+    let mut z = h[(low + 1, low)] * h[(low + 2, low + 1)];
+    for k in low..last - 1 {
+        let first_col = if k > low { k - 1 } else { low };
+        reflect(h, &[x, y, z], k, first_col..high, low..high.min(k + 4));
+        if k > low {
+            h[(k + 1, k - 1)] = 0.0;
+            h[(k + 2, k - 1)] = 0.0;
+        }
+        x = h[(k + 1, k)];
+        y = h[(k + 2, k)];
+        if k + 3 < high {
+            z = h[(k + 3, k)];
+        }
+    }
+    reflect(h, &[x, y], last - 1, last - 2..high, low..high);
+    h[(last, last - 2)] = 0.0;
+}
+
+// Apply the Householder reflection that takes `vector` to a multiple of the first unit vector
+// to rows `start..start + vector.len()` from the left, restricted to the columns `cols`,
+// and to the same columns from the right, restricted to the rows `rows`
+fn reflect(h: &mut Matrix<f64>, vector: &[f64], start: usize, cols: std::ops::Range<usize>, rows: std::ops::Range<usize>) {
+    let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return;
+    }
+    let mut v = vector.to_vec();
+    v[0] += norm.copysign(v[0]);
+    let v_norm: f64 = v.iter().map(|x| x * x).sum();
+    for col in cols {
+        let dot: f64 = v.iter().enumerate().map(|(i, x)| x * h[(start + i, col)]).sum();
+        let factor = 2.0 * dot / v_norm;
+        for (i, x) in v.iter().enumerate() {
+            h[(start + i, col)] -= factor * x;
+        }
+    }
+    for row in rows {
+        let dot: f64 = v.iter().enumerate().map(|(i, x)| x * h[(row, start + i)]).sum();
+        let factor = 2.0 * dot / v_norm;
+        for (i, x) in v.iter().enumerate() {
+            h[(row, start + i)] -= factor * x;
+        }
+    }
+}
@@ -9,11 +9,11 @@ use lemonmath::fraction::Fraction;
 // Create a fraction from a numerator and denominator
 let x = Fraction::new(1, 2);
 // Create a fraction from a float
-let y = Fraction::from_float(10.2082);
+let y = Fraction::from_float(5.0);
 
 // You can display fractions
 println!("{}", x);
-aseert_eq!(format!("{}", x), "1/2");
+assert_eq!(format!("{}", x), "1/2");
 
 // You can add fractions
 let z = x + y;
@@ -25,11 +25,11 @@ assert_eq!(z, Fraction::new(-9, 2));
 
 // You can multiply fractions
 let z = x * y;
-assert_eq!(z, Fraction::new(10, 1));
+assert_eq!(z, Fraction::new(5, 2));
 
 // You can divide fractions
 let z = x / y;
-assert_eq!(z, Fraction::new(5, 1));
+assert_eq!(z, Fraction::new(1, 10));
 
 // You can add assign fractions
 let mut x = Fraction::new(1, 2);
@@ -44,24 +44,32 @@ assert_eq!(x, Fraction::new(-9, 2));
 // You can mul assign fractions
 let mut x = Fraction::new(1, 2);
 x *= y;
-assert_eq!(x, Fraction::new(10, 1));
+assert_eq!(x, Fraction::new(5, 2));
 
 // You can div assign fractions
 let mut x = Fraction::new(1, 2);
 x /= y;
-assert_eq!(x, Fraction::new(5, 1));
+assert_eq!(x, Fraction::new(1, 10));
 ```
 */
 
 use std::{ops::{Add, Sub, Mul, Div, AddAssign, DivAssign, SubAssign, MulAssign, Neg}, fmt::{Formatter, Display}};
 
-use crate::helper::{GetDecimal, GCD};
+use crate::helper::{wide_div_rem, widening_mul, GetDecimal, GCD};
 
 #[test]
 fn fraction_test() {
     let x = Fraction::from_float(4.0);
-    let y = Fraction::from_float(1.0);
     println!("{}", x.sqrt());
+
+    // Sums whose cross products overflow, but whose reduced result fits
+    let big = 1i128 << 124;
+    assert_eq!(Fraction::new(3 * big + 1, 3) + Fraction::new(-5 * big - 1, 5), Fraction::new(2, 15));
+    assert_eq!(Fraction::new(-3 * big - 1, 3) + Fraction::new(5 * big + 1, 5), Fraction::new(-2, 15));
+    assert_eq!(Fraction::new(3 * big + 1, 3) - Fraction::new(3 * big + 1, 3), Fraction::new(0, 1));
+    // The factor shared by the denominators cancels with the 256 bit numerator
+    assert_eq!(Fraction::new(3 * big + 1, 6) + Fraction::new(-5 * big - 1, 10), Fraction::new(1, 15));
+    assert!(std::panic::catch_unwind(|| Fraction::new(i128::MAX, 1) + Fraction::new(i128::MAX, 3)).is_err());
 }
 
 /// A struct to replace floats
@@ -97,7 +105,11 @@ impl Fraction {
             denominator: denominator.abs(),
         }.reduce();
     }
-    /// This creates a new Fraction from a float
+    /// This creates a new Fraction from a float, using the shortest decimal that reads back as the same float
+    /// 
+    /// Digits that don't fit in an `i128` are rounded off, and NaN becomes 0.
+    /// Values with an integer part that doesn't fit in an `i128`, including the infinities,
+    /// saturate to `±i128::MAX`; use [`Fraction::checked_from_float`] to catch them instead
     /// 
     /// # Examples
     /// ```
@@ -107,15 +119,53 @@ impl Fraction {
     /// 
     /// assert_eq!(x.numerator, 1);
     /// assert_eq!(x.denominator, 1);
+    /// assert_eq!(Fraction::from_float(1.05), Fraction::new(21, 20));
+    /// assert_eq!(Fraction::from_float(-0.5), Fraction::new(-1, 2));
+    /// assert_eq!(Fraction::from_float(1e-10), Fraction::new(1, 10_000_000_000));
+    /// 
+    /// assert_eq!(Fraction::from_float(f64::INFINITY), Fraction::new(i128::MAX, 1));
+    /// assert_eq!(Fraction::from_float(-1e300), Fraction::new(-i128::MAX, 1));
     /// ```
     pub fn from_float(value: f64) -> Self {
-        let ten_pow = 10_u32.pow(format!("{}", value.get_decimal()).len() as u32);
-        let numerator = (value.trunc() as i128 * ten_pow as i128) + (value.get_decimal() as i128);
-        let denominator = ten_pow as i128;
-        return Fraction::new(numerator, denominator).reduce();
+        let (mut digits, mut scale) = value.get_decimal();
+        let integer = value.trunc().abs() as i128;
+        let sign = if value < 0.0 { -1 } else { 1 };
+        loop {
+            let denominator = 10i128.checked_pow(scale);
+            let numerator = denominator.and_then(|x| integer.checked_mul(x)).and_then(|x| x.checked_add(digits as i128));
+            if let (Some(numerator), Some(denominator)) = (numerator, denominator) {
+                return Fraction::new(sign * numerator, denominator);
+            }
+            // Round off the last digit
+            digits = (digits + 5) / 10;
+            scale -= 1;
+        }
+    }
+    /// This creates a new Fraction from a float like [`Fraction::from_float`],
+    /// or returns `None` if the float is NaN, infinite or its integer part doesn't fit in an `i128`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::checked_from_float(0.25), Some(Fraction::new(1, 4)));
+    /// assert_eq!(Fraction::checked_from_float(-2f64.powi(126)), Some(Fraction::new(-1 << 126, 1)));
+    /// assert_eq!(Fraction::checked_from_float(1.7976931348623157e308), None);
+    /// assert_eq!(Fraction::checked_from_float(f64::INFINITY), None);
+    /// assert_eq!(Fraction::checked_from_float(f64::NAN), None);
+    /// ```
+    pub fn checked_from_float(value: f64) -> Option<Self> {
+        // i128::MAX rounds up to 2^127 as a float, so anything below it truncates to a value that fits
+        if !value.is_finite() || value.abs() >= i128::MAX as f64 {
+            return None;
+        }
+        return Some(Fraction::from_float(value));
     }
     /// This adds two fractions together
     /// 
+    /// The denominators are divided by their gcd first, and the cross products are taken in 256 bits when they don't fit,
+    /// so the sum only overflows if the result does
+    /// 
     /// # Examples
     /// ```
     /// use lemonmath::fraction::Fraction;
@@ -124,17 +174,34 @@ impl Fraction {
     /// let y = Fraction::new(2, 3);
     /// 
     /// assert_eq!(x.add_number(y), Fraction::new(7, 6));
+    /// 
+    /// let z = Fraction::new(1, 1 << 64);
+    /// 
+    /// assert_eq!(z.add_number(z), Fraction::new(1, 1 << 63));
+    /// 
+    /// // The cross products overflow, but the sum doesn't
+    /// let big = 1 << 124;
+    /// 
+    /// assert_eq!(Fraction::new(3 * big + 1, 3).add_number(Fraction::new(-5 * big - 1, 5)), Fraction::new(2, 15));
     /// ```
     pub fn add_number(&self, other: Self) -> Self {
-        let mut numerator = self.numerator * other.denominator + other.numerator * self.denominator;
-        let mut denominator = self.denominator * other.denominator;
-        let gcd = numerator.gcd(denominator);
-        numerator /= gcd;
-        denominator /= gcd;
-        return Fraction::new(numerator, denominator);
+        let gcd = self.denominator.gcd(other.denominator);
+        let (left, right) = (other.denominator / gcd, self.denominator / gcd);
+        let sum = self.numerator.checked_mul(left).and_then(|x| x.checked_add(other.numerator.checked_mul(right)?));
+        let (numerator, cancel) = match sum {
+            // Only factors of the shared part of the denominators can cancel
+            Some(numerator) => (numerator / numerator.gcd(gcd), numerator.gcd(gcd)),
+            None => match wide_sum(self.numerator, left, other.numerator, right, gcd) {
+                Some(result) => result,
+                None => panic!("The sum of {:?} and {:?} doesn't fit in a fraction", self, other),
+            },
+        };
+        return Fraction::new(numerator, right * (other.denominator / cancel));
     }
     /// This multiplies two fractions together
     /// 
+    /// Each numerator is cross-reduced with the other denominator first, so the product only overflows if the result does
+    /// 
     /// # Examples
     /// ```
     /// use lemonmath::fraction::Fraction;
@@ -143,13 +210,16 @@ impl Fraction {
     /// let y = Fraction::new(2, 3);
     /// 
     /// assert_eq!(x.mul_number(y), Fraction::new(1, 3));
+    /// 
+    /// let z = Fraction::new(1 << 70, 3);
+    /// 
+    /// assert_eq!(z.mul_number(Fraction::new(3, 1 << 70)), Fraction::new(1, 1));
     /// ```
     pub fn mul_number(&self, other: Self) -> Self {
-        let mut numerator = self.numerator * other.numerator;
-        let mut denominator = self.denominator * other.denominator;
-        let gcd = numerator.gcd(denominator);
-        numerator /= gcd;
-        denominator /= gcd;
+        let first = self.numerator.gcd(other.denominator);
+        let second = other.numerator.gcd(self.denominator);
+        let numerator = (self.numerator / first) * (other.numerator / second);
+        let denominator = (self.denominator / second) * (other.denominator / first);
         return Fraction::new(numerator, denominator);
     }
     /// This divides two fractions
@@ -302,7 +372,7 @@ impl Display for Fraction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let denominator = match self.denominator {
             1 => String::new(),
-            _ => String::from("/".to_owned() + &self.denominator.to_string()),
+            _ => "/".to_owned() + &self.denominator.to_string(),
         };
         write!(f, "{}{}", self.numerator, denominator)
     }
@@ -418,4 +488,87 @@ impl Neg for Fraction {
     fn neg(self) -> Self::Output {
         return Fraction::new(-self.numerator, self.denominator);
     }
-}
\ No newline at end of file
+}
+
+impl PartialOrd for Fraction {
+    /// This compares two fractions by value
+    /// 
+    /// The signs are compared first, then the cross products of the magnitudes in 256 bits, so it never overflows
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::fraction::Fraction;
+    /// 
+    /// assert!(Fraction::new(1, 3) < Fraction::new(1, 2));
+    /// assert!(Fraction::new(-1, 2) < Fraction::new(-1, 3));
+    /// assert!(Fraction::new(i128::MAX, 3) < Fraction::new(i128::MAX, 2));
+    /// assert!(Fraction::new(i128::MIN, i128::MAX) < Fraction::new(-1, 1));
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let sign = |x: &Fraction| x.numerator.signum() * x.denominator.signum();
+        let (self_sign, other_sign) = (sign(self), sign(other));
+        if self_sign != other_sign {
+            return Some(self_sign.cmp(&other_sign));
+        }
+        let left = widening_mul(self.numerator.unsigned_abs(), other.denominator.unsigned_abs());
+        let right = widening_mul(other.numerator.unsigned_abs(), self.denominator.unsigned_abs());
+        return Some(match self_sign < 0 {
+            true => right.cmp(&left),
+            false => left.cmp(&right),
+        });
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name<$t> for Fraction {
+            /// This creates a whole number fraction from an integer
+            fn from(value: $t) -> Self {
+                return Fraction::new(value as i128, 1);
+            }
+        }
+    )*)
+}
+
+impl_from_integer!(From for u8 u16 u32 u64 usize i8 i16 i32 i64 i128 isize);
+
+impl TryFrom<u128> for Fraction {
+    type Error = std::num::TryFromIntError;
+    /// This creates a whole number fraction from a `u128`, if it fits in an `i128`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::fraction::Fraction;
+    /// 
+    /// assert_eq!(Fraction::try_from(5u128), Ok(Fraction::new(5, 1)));
+    /// assert!(Fraction::try_from(u128::MAX).is_err());
+    /// ```
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        return Ok(Fraction::new(i128::try_from(value)?, 1));
+    }
+}
+
+// The reduced numerator and the cancelled factor of the sum a * x + b * y over the shared denominator gcd,
+// with the cross products taken in 256 bits, or None if the reduced numerator doesn't fit
+fn wide_sum(a: i128, x: i128, b: i128, y: i128, gcd: i128) -> Option<(i128, i128)> {
+    let first = widening_mul(a.unsigned_abs(), x as u128);
+    let second = widening_mul(b.unsigned_abs(), y as u128);
+    // Both products are below 2^254, so their sum or difference fits in 256 bits
+    let (negative, (high, low)) = if (a < 0) == (b < 0) {
+        let (low, carry) = first.1.overflowing_add(second.1);
+        (a < 0, (first.0 + second.0 + carry as u128, low))
+    } else if first >= second {
+        let (low, borrow) = first.1.overflowing_sub(second.1);
+        (a < 0, (first.0 - second.0 - borrow as u128, low))
+    } else {
+        let (low, borrow) = second.1.overflowing_sub(first.1);
+        (b < 0, (second.0 - first.0 - borrow as u128, low))
+    };
+    let modulus = gcd as u128;
+    let cancel = wide_div_rem(high % modulus, low, modulus).1.gcd(modulus);
+    if high >= cancel {
+        return None;
+    }
+    let magnitude = i128::try_from(wide_div_rem(high, low, cancel).0).ok()?;
+    return Some((if negative { -magnitude } else { magnitude }, cancel as i128));
+}
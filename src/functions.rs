@@ -0,0 +1,243 @@
+/*!
+# Functions
+Powers, exponentials, square roots and logarithms of square matrices
+
+# Examples
+```rust
+use lemonmath::matrices::Matrix;
+use lemonmath::fraction::Fraction;
+
+// Exact powers of a transition matrix
+let p = Matrix::new(2, 2, vec![Fraction::new(1, 2), Fraction::new(1, 2), Fraction::new(1, 4), Fraction::new(3, 4)]);
+let p2 = p.pow(2).unwrap();
+
+assert_eq!(p2, Matrix::new(2, 2, vec![Fraction::new(3, 8), Fraction::new(5, 8), Fraction::new(5, 16), Fraction::new(11, 16)]));
+
+// exp(At) of a rotation generator is a rotation
+let t = std::f64::consts::FRAC_PI_2;
+let a = Matrix::new(2, 2, vec![0.0, -t, t, 0.0]);
+let rotation = a.exp().unwrap();
+let expected = Matrix::new(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+
+assert!((rotation - expected).content().iter().all(|x| x.abs() < 1e-12));
+
+// The logarithm undoes the exponential
+let b = Matrix::new(2, 2, vec![1.0, 0.5, 0.0, 2.0]);
+let log = b.exp().unwrap().logm().unwrap();
+
+assert!((log - b).content().iter().all(|x| x.abs() < 1e-10));
+```
+*/
+
+use std::ops::{AddAssign, Mul};
+
+use crate::matrices::{Matrix, MatrixError};
+
+const MAX_ITERATIONS: usize = 100;
+
+// The degree of the diagonal Padé approximant used by `exp`
+const PADE_DEGREE: i32 = 6;
+
+// Enough halvings to bring any finite norm below 1/2
+const MAX_SQUARINGS: i32 = f64::MAX_EXP + 2;
+
+impl<T> Matrix<T>
+where
+    T: AddAssign + Default + Mul<Output = T> + From<u8> + Copy,
+{
+    /// Raise the matrix to a non-negative integer power by repeated squaring
+    /// 
+    /// This only takes about `2 log2(n)` multiplications, and is exact for fraction and integer matrices
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// // Fibonacci numbers
+    /// let a = Matrix::new(2, 2, vec![1u64, 1, 1, 0]);
+    /// 
+    /// assert_eq!(a.pow(10), Ok(Matrix::new(2, 2, vec![89, 55, 55, 34])));
+    /// assert_eq!(a.pow(0), Ok(Matrix::identity(2)));
+    /// ```
+    pub fn pow(&self, mut n: u32) -> Result<Matrix<T>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let mut result = Matrix::identity(self.rows());
+        let mut base = self.clone();
+        while n > 0 {
+            if n % 2 == 1 {
+                result = result * base.clone();
+            }
+            n /= 2;
+            if n > 0 {
+                base = base.clone() * base;
+            }
+        }
+        return Ok(result);
+    }
+}
+
+impl Matrix<f64> {
+    /// The matrix exponential, using scaling and squaring with a diagonal Padé approximant
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// and [`MatrixError::Overflow`] if an entry is infinite or NaN, or the row sums overflow
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::{Matrix, MatrixError};
+    /// 
+    /// let a = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 2.0]);
+    /// let exp = a.exp().unwrap();
+    /// 
+    /// assert!((exp[(0, 0)] - 1f64.exp()).abs() < 1e-12);
+    /// assert!((exp[(1, 1)] - 2f64.exp()).abs() < 1e-12);
+    /// assert_eq!(exp[(0, 1)], 0.0);
+    /// 
+    /// // A rotation generator with a norm just below 1
+    /// let t = 0.99f64;
+    /// let exp = Matrix::new(2, 2, vec![0.0, -t, t, 0.0]).exp().unwrap();
+    /// let expected = Matrix::new(2, 2, vec![t.cos(), -t.sin(), t.sin(), t.cos()]);
+    /// 
+    /// assert!((exp - expected).content().iter().all(|x| x.abs() < 1e-15));
+    /// 
+    /// assert_eq!(Matrix::new(1, 1, vec![f64::INFINITY]).exp(), Err(MatrixError::Overflow));
+    /// assert_eq!(Matrix::new(1, 1, vec![f64::NAN]).exp(), Err(MatrixError::Overflow));
+    /// ```
+    pub fn exp(&self) -> Result<Matrix<f64>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        let norm = norm_inf(self);
+        if !norm.is_finite() || self.content().iter().any(|x| x.is_nan()) {
+            return Err(MatrixError::Overflow);
+        }
+        // The degree 6 approximant is accurate to double precision for norms up to about 1/2
+        let squarings = if norm > 0.0 { (norm.log2().floor() as i32 + 2).clamp(0, MAX_SQUARINGS) } else { 0 };
+        let a = scale(self, 0.5f64.powi(squarings));
+        let mut numerator: Matrix<f64> = Matrix::identity(size);
+        let mut denominator: Matrix<f64> = Matrix::identity(size);
+        let mut power: Matrix<f64> = Matrix::identity(size);
+        let mut c = 1.0;
+        for k in 1..=PADE_DEGREE {
+            c *= (PADE_DEGREE - k + 1) as f64 / ((2 * PADE_DEGREE - k + 1) * k) as f64;
+            power = a.clone() * power;
+            numerator = numerator + scale(&power, c);
+            denominator = denominator + scale(&power, if k % 2 == 0 { c } else { -c });
+        }
+        let mut result = denominator.lu()?.inverse()? * numerator;
+        for _ in 0..squarings {
+            result = result.clone() * result;
+        }
+        return Ok(result);
+    }
+    /// The principal square root, using the Denman–Beavers iteration
+    /// 
+    /// The matrix should have no eigenvalues on the closed negative real axis
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square,
+    /// [`MatrixError::Singular`] if an iterate can't be inverted
+    /// and [`MatrixError::NoConvergence`] if the iteration doesn't settle
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![4.0, 1.0, 0.0, 9.0]);
+    /// let root = a.sqrtm().unwrap();
+    /// let expected = Matrix::new(2, 2, vec![2.0, 0.2, 0.0, 3.0]);
+    /// 
+    /// assert!((root.clone() - expected).content().iter().all(|x| x.abs() < 1e-12));
+    /// assert!((root.clone() * root - a).content().iter().all(|x| x.abs() < 1e-12));
+    /// ```
+    pub fn sqrtm(&self) -> Result<Matrix<f64>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        let mut y = self.clone();
+        let mut z: Matrix<f64> = Matrix::identity(size);
+        for _ in 0..MAX_ITERATIONS {
+            let y_inverse = y.lu()?.inverse()?;
+            let z_inverse = z.lu()?.inverse()?;
+            let next = scale(&(y.clone() + z_inverse), 0.5);
+            z = scale(&(z + y_inverse), 0.5);
+            let change = norm_inf(&(next.clone() - y));
+            y = next;
+            // Rounding keeps the iterates from settling exactly, so allow a few ulps per entry
+            if change <= 10.0 * size as f64 * f64::EPSILON * norm_inf(&y) {
+                return Ok(y);
+            }
+        }
+        return Err(MatrixError::NoConvergence);
+    }
+    /// The principal logarithm, using inverse scaling and squaring
+    /// 
+    /// Square roots are taken until the matrix is close to the identity, and the logarithm series is summed there.
+    /// This is meant for well-conditioned matrices with no eigenvalues on the closed negative real axis
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square,
+    /// [`MatrixError::Singular`] if the matrix is singular
+    /// and [`MatrixError::NoConvergence`] if the matrix can't be brought close to the identity
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![std::f64::consts::E, 0.0, 0.0, 1.0]);
+    /// let log = a.logm().unwrap();
+    /// let expected = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 0.0]);
+    /// 
+    /// assert!((log - expected).content().iter().all(|x| x.abs() < 1e-12));
+    /// ```
+    pub fn logm(&self) -> Result<Matrix<f64>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        let identity: Matrix<f64> = Matrix::identity(size);
+        let mut a = self.clone();
+        let mut roots = 0;
+        while norm_inf(&(a.clone() - identity.clone())) > 0.25 {
+            if roots == 64 {
+                return Err(MatrixError::NoConvergence);
+            }
+            a = a.sqrtm()?;
+            roots += 1;
+        }
+        // log(I + X) = X - X^2/2 + X^3/3 - ...
+        let x = a - identity;
+        let mut power = x.clone();
+        let mut result = x.clone();
+        for k in 2..=MAX_ITERATIONS {
+            power = power * x.clone();
+            let term = scale(&power, if k % 2 == 0 { -1.0 } else { 1.0 } / k as f64);
+            result = result + term.clone();
+            if norm_inf(&term) <= f64::EPSILON * norm_inf(&result) {
+                return Ok(scale(&result, 2f64.powi(roots)));
+            }
+        }
+        return Err(MatrixError::NoConvergence);
+    }
+}
+
+// The maximum absolute row sum
+fn norm_inf(matrix: &Matrix<f64>) -> f64 {
+    let mut result: f64 = 0.0;
+    for row in 0..matrix.rows() {
+        result = result.max((0..matrix.cols()).map(|col| matrix[(row, col)].abs()).sum());
+    }
+    return result;
+}
+
+fn scale(matrix: &Matrix<f64>, factor: f64) -> Matrix<f64> {
+    return Matrix::new(matrix.rows(), matrix.cols(), matrix.content().iter().map(|x| x * factor).collect());
+}
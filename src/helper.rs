@@ -4,31 +4,58 @@ An assortment of helper functions used in the library
 
 use crate::fraction::Fraction;
 
-/// A trait to losslessly get the decimal part of a float
+/// A trait to get the decimal part of a float as its digits and scale
+/// 
+/// Returns `(digits, scale)` where the fractional part of the absolute value is `digits / 10^scale`,
+/// using the shortest decimal that reads back as the same float. Whole numbers, infinities and NaN give `(0, 0)`
 /// 
 /// # Examples
 /// ```
 /// use lemonmath::helper::GetDecimal;
 /// 
-/// let x = 1.12;
-/// 
-/// assert_eq!(x.get_decimal(), 12);
+/// assert_eq!(1.12.get_decimal(), (12, 2));
+/// assert_eq!(1.05.get_decimal(), (5, 2));
+/// assert_eq!(1.5.get_decimal(), (5, 1));
+/// assert_eq!((-2.25).get_decimal(), (25, 2));
+/// assert_eq!(1e-10.get_decimal(), (1, 10));
+/// assert_eq!(3.0.get_decimal(), (0, 0));
+/// assert_eq!(0.1f32.get_decimal(), (1, 1));
 /// ```
 pub trait GetDecimal {
-    fn get_decimal(&self) -> u128;
+    fn get_decimal(&self) -> (u128, u32);
 }
 
+// The largest scale tried, enough for the 17 significant digits after the leading zeros of the smallest subnormal
+const MAX_DECIMAL_SCALE: i32 = 342;
+
 macro_rules! impl_get_decimal {
     ($name:ident for $($t:ty)*) => ($(
         impl $name for $t {
-            fn get_decimal(&self) -> u128 {
-                if self.floor().to_string() != self.to_string() {
-                    let decimalstring = &self.to_string()[(self.floor()).to_string().len()+1..];
-                    let decimalnumber = decimalstring.parse::<u128>().unwrap();
-                    return decimalnumber;
-                } else {
-                    return 0u128;
+            fn get_decimal(&self) -> (u128, u32) {
+                if !self.is_finite() {
+                    return (0, 0);
+                }
+                let value = self.abs();
+                let integer = value.trunc() as f64;
+                let fraction = value as f64 - integer;
+                if fraction == 0.0 {
+                    return (0, 0);
+                }
+                let mut digits = 0.0;
+                for scale in 1..=MAX_DECIMAL_SCALE {
+                    digits = scale_by_ten(fraction, scale).round();
+                    // A single division of exact values rounds the same way as reading the decimal back in
+                    let whole = integer * 10f64.powi(scale) + digits;
+                    let candidate = if scale <= 22 && whole < 9007199254740992.0 {
+                        whole / 10f64.powi(scale)
+                    } else {
+                        integer + scale_by_ten(digits, -scale)
+                    };
+                    if candidate as $t == value {
+                        return (digits as u128, scale as u32);
+                    }
                 }
+                return (digits as u128, MAX_DECIMAL_SCALE as u32);
             }
         }
     )*)
@@ -36,35 +63,229 @@ macro_rules! impl_get_decimal {
 
 impl_get_decimal!(GetDecimal for f32 f64);
 
-/// Exponents for unsigned integers
+// Multiply by a power of ten in steps of at most 10^22, the largest exact one, so nothing overflows on the way
+fn scale_by_ten(mut value: f64, mut exponent: i32) -> f64 {
+    while exponent != 0 {
+        let step = exponent.clamp(-22, 22);
+        if step > 0 {
+            value *= 10f64.powi(step);
+        } else {
+            value /= 10f64.powi(-step);
+        }
+        exponent -= step;
+    }
+    return value;
+}
+
+/// Exponents by repeated squaring, computed in the type itself
+/// 
+/// `power` overflows the same way as multiplication does, `checked_power` returns `None` on overflow
+/// and `saturating_power` clamps to the nearest bound
 /// 
 /// # Examples
 /// ```
 /// use lemonmath::helper::BetterExponent;
+/// use lemonmath::fraction::Fraction;
 /// 
-/// let x = 2u32.pow(3);
+/// assert_eq!(2u32.power(3), 8);
+/// assert_eq!(3u128.power(80), 147808829414345923316083210206383297601);
+/// assert_eq!((-2i8).power(7), -128);
 /// 
-/// assert_eq!(x, 8);
+/// assert_eq!(2u8.checked_power(8), None);
+/// assert_eq!(2u8.saturating_power(8), 255);
+/// assert_eq!((-2i8).saturating_power(9), -128);
+/// 
+/// assert_eq!(Fraction::new(-2, 3).power(3), Fraction::new(-8, 27));
+/// assert_eq!(Fraction::new(1, 2).checked_power(200), None);
+/// assert_eq!(Fraction::new(1, 2).saturating_power(200), Fraction::new(0, 1));
 /// ```
-pub trait BetterExponent {
-    fn power(self, number: usize) -> Self;
+pub trait BetterExponent: Sized {
+    fn power(self, exponent: usize) -> Self;
+    fn checked_power(self, exponent: usize) -> Option<Self>;
+    fn saturating_power(self, exponent: usize) -> Self;
+}
+
+/// Exponents in modular arithmetic
+/// 
+/// `wrapping_power` works modulo `2^bits` like the other wrapping operations,
+/// and `mod_pow` works modulo any number, returning a result between 0 and the modulus.
+/// Products for `mod_pow` are taken in 256 bits, so any `u128` modulus works without overflowing
+/// 
+/// # Panics
+/// `mod_pow` panics if the modulus is 0
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::helper::ModularExponent;
+/// 
+/// assert_eq!(3u8.wrapping_power(5), 243);
+/// assert_eq!(3u8.wrapping_power(6), 217);
+/// 
+/// assert_eq!(4u32.mod_pow(13, 497), 445);
+/// assert_eq!((-3i64).mod_pow(3, 5), 3);
+/// 
+/// // 2^127 is 1 modulo 2^127 - 1, so 2^200 is 2^73
+/// let p = u128::MAX >> 1;
+/// 
+/// assert_eq!((1u128 << 100).mod_pow(2, p), 1 << 73);
+/// assert_eq!(123456789u128.mod_pow(0, p), 1);
+/// ```
+pub trait ModularExponent: Sized {
+    fn wrapping_power(self, exponent: usize) -> Self;
+    fn mod_pow(self, exponent: usize, modulus: Self) -> Self;
+}
+
+/// Repeated squaring with a given multiplication, stopping at the first `None`
+pub(crate) fn square_and_multiply<T: Copy, F: Fn(T, T) -> Option<T>>(base: T, one: T, mut exponent: u128, mul: F) -> Option<T> {
+    let mut result = one;
+    let mut base = base;
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = mul(result, base)?;
+        }
+        exponent /= 2;
+        if exponent > 0 {
+            base = mul(base, base)?;
+        }
+    }
+    return Some(result);
 }
 
 macro_rules! impl_better_exponent {
     ($name:ident for $($t:ty)*) => ($(
         impl $name for $t {
-            fn power(self, number: usize) -> Self {
-                let mut result = 1usize;
-                for _ in 1..=number {
-                    result *= self as usize;
+            fn power(self, exponent: usize) -> Self {
+                return square_and_multiply(self, 1, exponent as u128, |a, b| Some(a * b)).unwrap();
+            }
+            fn checked_power(self, exponent: usize) -> Option<Self> {
+                return square_and_multiply(self, 1, exponent as u128, |a, b| a.checked_mul(b));
+            }
+            fn saturating_power(self, exponent: usize) -> Self {
+                match self.checked_power(exponent) {
+                    Some(result) => return result,
+                    // Only a negative base to an odd power overflows downwards
+                    None if self < 1 && exponent % 2 == 1 => return <$t>::MIN,
+                    None => return <$t>::MAX,
+                }
+            }
+        }
+    )*)
+}
+
+impl_better_exponent!(BetterExponent for u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_modular_exponent {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn wrapping_power(self, exponent: usize) -> Self {
+                return square_and_multiply(self, 1, exponent as u128, |a, b| Some(a.wrapping_mul(b))).unwrap();
+            }
+            fn mod_pow(self, exponent: usize, modulus: Self) -> Self {
+                if modulus == 0 {
+                    panic!("The modulus can't be 0");
+                }
+                // The result is below the size of the modulus, so it fits back into the type
+                let modulus = modulus.unsigned_abs() as u128;
+                let base = match self < 0 {
+                    true => (modulus - self.unsigned_abs() as u128 % modulus) % modulus,
+                    false => self as u128,
+                };
+                return pow_mod(base, exponent as u128, modulus) as $t;
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_unsigned_modular_exponent {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn wrapping_power(self, exponent: usize) -> Self {
+                return square_and_multiply(self, 1, exponent as u128, |a, b| Some(a.wrapping_mul(b))).unwrap();
+            }
+            fn mod_pow(self, exponent: usize, modulus: Self) -> Self {
+                if modulus == 0 {
+                    panic!("The modulus can't be 0");
                 }
-                return result as Self;
+                return pow_mod(self as u128, exponent as u128, modulus as u128) as $t;
             }
         }
     )*)
 }
 
-impl_better_exponent!(BetterExponent for u8 u16 u32 u64 u128);
+impl_unsigned_modular_exponent!(ModularExponent for u8 u16 u32 u64 u128 usize);
+impl_modular_exponent!(ModularExponent for i8 i16 i32 i64 i128 isize);
+
+/// `base^exponent % modulus` for a non-zero modulus, with every product taken by [`mul_mod`]
+pub(crate) fn pow_mod(base: u128, exponent: u128, modulus: u128) -> u128 {
+    return square_and_multiply(base % modulus, 1 % modulus, exponent, |a, b| Some(mul_mod(a, b, modulus))).unwrap();
+}
+
+/// The full 256 bit product of two numbers, as its high and low words
+pub(crate) fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    // Split into 64 bit halves to get the high and low words of the product
+    let mask = u64::MAX as u128;
+    let (a_high, a_low, b_high, b_low) = (a >> 64, a & mask, b >> 64, b & mask);
+    let low = a_low * b_low;
+    let middle_one = a_high * b_low;
+    let middle_two = a_low * b_high;
+    let (middle, middle_carry) = middle_one.overflowing_add(middle_two);
+    let (low, low_carry) = low.overflowing_add(middle << 64);
+    let high = a_high * b_high + (middle >> 64) + ((middle_carry as u128) << 64) + low_carry as u128;
+    return (high, low);
+}
+
+/// `a * b % modulus` without overflowing, taking the product in 256 bits when it doesn't fit in 128
+pub(crate) fn mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    let (high, low) = widening_mul(a, b);
+    return wide_div_rem(high % modulus, low, modulus).1;
+}
+
+/// The quotient and remainder of the 256 bit number `high * 2^128 + low` divided by `divisor`,
+/// where `high < divisor` so the quotient fits in 128 bits
+pub(crate) fn wide_div_rem(high: u128, low: u128, divisor: u128) -> (u128, u128) {
+    // Long division, one bit of the low word at a time
+    let mut quotient = 0;
+    let mut remainder = high;
+    for bit in (0..128).rev() {
+        let (doubled, carry) = remainder.overflowing_add(remainder);
+        // Doubling leaves the lowest bit clear, so the next bit can't carry
+        remainder = doubled | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry || remainder >= divisor {
+            remainder = remainder.wrapping_sub(divisor);
+            quotient |= 1;
+        }
+    }
+    return (quotient, remainder);
+}
+
+impl BetterExponent for Fraction {
+    fn power(self, exponent: usize) -> Self {
+        // Powers of a reduced fraction are already reduced
+        return Fraction {
+            numerator: self.numerator.power(exponent),
+            denominator: self.denominator.power(exponent),
+        };
+    }
+    fn checked_power(self, exponent: usize) -> Option<Self> {
+        return Some(Fraction {
+            numerator: self.numerator.checked_power(exponent)?,
+            denominator: self.denominator.checked_power(exponent)?,
+        });
+    }
+    fn saturating_power(self, exponent: usize) -> Self {
+        match (self.numerator.checked_power(exponent), self.denominator.checked_power(exponent)) {
+            (Some(numerator), Some(denominator)) => return Fraction { numerator, denominator },
+            // Whichever part is larger in size decides whether the power grows past the bounds or shrinks towards 0
+            (None, Some(_)) => return Fraction::new(self.numerator.saturating_power(exponent), 1),
+            (None, None) if self.numerator.unsigned_abs() > self.denominator.unsigned_abs() => return Fraction::new(self.numerator.saturating_power(exponent), 1),
+            _ => return Fraction::new(0, 1),
+        }
+    }
+}
 
 /// This turns a Vector of numbers into a Vector of Fractions
 /// 
@@ -103,6 +324,8 @@ impl_vec_to_fraction!(VecToFraction for u8 u16 u32 u64 u128 i8 i16 i32 i64 i128
 
 /// This is a helper trait to find gcd of two numbers
 /// 
+/// The gcd is never negative, and the gcd of 0 and 0 is 0
+/// 
 /// # Examples
 /// ```
 /// use lemonmath::helper::GCD;
@@ -110,6 +333,9 @@ impl_vec_to_fraction!(VecToFraction for u8 u16 u32 u64 u128 i8 i16 i32 i64 i128
 /// let x = (2, 4);
 ///
 /// assert_eq!(x.0.gcd(x.1), 2); 
+/// assert_eq!((-12i64).gcd(18), 6);
+/// assert_eq!(48u8.gcd(180), 12);
+/// assert_eq!(0u128.gcd(0), 0);
 /// ```
 pub trait GCD {
     fn gcd(self, other: Self) -> Self;
@@ -146,7 +372,7 @@ macro_rules! impl_gcd {
                 // Assuming two's complement, the number created by the shift
                 // is positive for all numbers except gcd = abs(min value)
                 // The call to .abs() causes a panic in debug mode
-                if m == Self::min_value() || n == Self::min_value() {
+                if m == Self::MIN || n == Self::MIN {
                     return ((1 << shift) as $t).abs();
                 }
             
@@ -173,4 +399,253 @@ macro_rules! impl_gcd {
     )*)
 }
 
-impl_gcd!(GCD for i8 i16 i32 i64 i128 isize);
\ No newline at end of file
+impl_gcd!(GCD for i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_unsigned_gcd {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn gcd(self, other: Self) -> Self {
+                // Stein's algorithm, as for the signed types
+                let mut m = self;
+                let mut n = other;
+                if m == 0 || n == 0 {
+                    return m | n;
+                }
+                let shift = (m | n).trailing_zeros();
+                m >>= m.trailing_zeros();
+                n >>= n.trailing_zeros();
+                while m != n {
+                    if m > n {
+                        m -= n;
+                        m >>= m.trailing_zeros();
+                    } else {
+                        n -= m;
+                        n >>= n.trailing_zeros();
+                    }
+                }
+                return m << shift;
+            }
+        }
+    )*)
+}
+
+impl_unsigned_gcd!(GCD for u8 u16 u32 u64 u128 usize);
+
+/// This is a helper trait to find the lcm of two numbers
+/// 
+/// The lcm is never negative, and is 0 if either number is 0.
+/// `lcm` panics if the result overflows, while `checked_lcm` returns `None`
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::helper::LCM;
+/// 
+/// assert_eq!(4.lcm(6), 12);
+/// assert_eq!((-4i32).lcm(6), 12);
+/// assert_eq!(0u8.lcm(5), 0);
+/// assert_eq!(200u8.checked_lcm(3), None);
+/// assert_eq!(i128::MIN.checked_lcm(i128::MIN), None);
+/// ```
+pub trait LCM: Sized {
+    fn lcm(self, other: Self) -> Self;
+    fn checked_lcm(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_lcm {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn lcm(self, other: Self) -> Self {
+                match self.checked_lcm(other) {
+                    Some(result) => return result,
+                    None => panic!("The lcm of {} and {} overflows", self, other),
+                }
+            }
+            fn checked_lcm(self, other: Self) -> Option<Self> {
+                if self == 0 || other == 0 {
+                    return Some(0);
+                }
+                // Any multiple of MIN is at least as large as MIN.abs(), which doesn't fit,
+                // and the gcd can't be taken when both are MIN
+                if self == <$t>::MIN || other == <$t>::MIN {
+                    return None;
+                }
+                // Divide first, so only the result itself can overflow
+                return (self / self.gcd(other)).checked_mul(other)?.checked_abs();
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_unsigned_lcm {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn lcm(self, other: Self) -> Self {
+                match self.checked_lcm(other) {
+                    Some(result) => return result,
+                    None => panic!("The lcm of {} and {} overflows", self, other),
+                }
+            }
+            fn checked_lcm(self, other: Self) -> Option<Self> {
+                if self == 0 || other == 0 {
+                    return Some(0);
+                }
+                return (self / self.gcd(other)).checked_mul(other);
+            }
+        }
+    )*)
+}
+
+impl_lcm!(LCM for i8 i16 i32 i64 i128 isize);
+impl_unsigned_lcm!(LCM for u8 u16 u32 u64 u128 usize);
+
+/// The gcd of all the numbers in a slice, which is 0 for an empty slice
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::helper::gcd_all;
+/// 
+/// assert_eq!(gcd_all(&[12, -18, 30]), 6);
+/// assert_eq!(gcd_all::<u32>(&[]), 0);
+/// ```
+pub fn gcd_all<T: GCD + Default + Copy>(values: &[T]) -> T {
+    return values.iter().fold(T::default(), |gcd, x| gcd.gcd(*x));
+}
+
+/// The lcm of all the numbers in a slice, which is 1 for an empty slice, or `None` if it overflows
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::helper::lcm_all;
+/// 
+/// assert_eq!(lcm_all(&[2u64, 3, 4, 5]), Some(60));
+/// assert_eq!(lcm_all(&[128u8, 3]), None);
+/// ```
+pub fn lcm_all<T: LCM + From<u8> + Copy>(values: &[T]) -> Option<T> {
+    let mut result = T::from(1);
+    for x in values {
+        result = result.checked_lcm(*x)?;
+    }
+    return Some(result);
+}
+
+/// This is a helper trait to find the gcd of two numbers together with their Bézout coefficients
+/// 
+/// Returns `(gcd, x, y)` where `self * x + other * y == gcd` and `gcd` is never negative.
+/// `extended_gcd` panics if the gcd or an intermediate value overflows, which can only happen
+/// when one of the numbers is `MIN`, while `checked_extended_gcd` returns `None`
+/// 
+/// Only the signed integers implement this, as one of the coefficients is negative unless a number divides the other.
+/// For unsigned numbers, [`ModInverse`] gives the coefficient reduced modulo the other number instead
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::helper::ExtendedGCD;
+/// 
+/// let (gcd, x, y) = 240.extended_gcd(46);
+/// 
+/// assert_eq!(gcd, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// assert_eq!(i128::MIN.checked_extended_gcd(0), None);
+/// ```
+pub trait ExtendedGCD: Sized {
+    fn extended_gcd(self, other: Self) -> (Self, Self, Self);
+    fn checked_extended_gcd(self, other: Self) -> Option<(Self, Self, Self)>;
+}
+
+macro_rules! impl_extended_gcd {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn extended_gcd(self, other: Self) -> (Self, Self, Self) {
+                match self.checked_extended_gcd(other) {
+                    Some(result) => return result,
+                    None => panic!("The extended gcd of {} and {} overflows", self, other),
+                }
+            }
+            fn checked_extended_gcd(self, other: Self) -> Option<(Self, Self, Self)> {
+                let (mut old_r, mut r) = (self, other);
+                let (mut old_s, mut s): (Self, Self) = (1, 0);
+                let (mut old_t, mut t): (Self, Self) = (0, 1);
+                while r != 0 {
+                    let quotient = old_r.checked_div(r)?;
+                    (old_r, r) = (r, old_r - quotient * r);
+                    (old_s, s) = (s, old_s.checked_sub(quotient.checked_mul(s)?)?);
+                    (old_t, t) = (t, old_t.checked_sub(quotient.checked_mul(t)?)?);
+                }
+                if old_r < 0 {
+                    return Some((old_r.checked_neg()?, old_s.checked_neg()?, old_t.checked_neg()?));
+                }
+                return Some((old_r, old_s, old_t));
+            }
+        }
+    )*)
+}
+
+impl_extended_gcd!(ExtendedGCD for i8 i16 i32 i64 i128 isize);
+
+/// This is a helper trait to find the inverse of a number modulo another
+/// 
+/// Returns the `x` between 0 and the modulus with `self * x ≡ 1`,
+/// or `None` if the modulus is 0 or the numbers aren't coprime
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::helper::ModInverse;
+/// 
+/// assert_eq!(3.mod_inverse(11), Some(4));
+/// assert_eq!((-3i64).mod_inverse(11), Some(7));
+/// assert_eq!(6u32.mod_inverse(9), None);
+/// assert_eq!(2u128.mod_inverse(u128::MAX), Some(1 << 127));
+/// ```
+pub trait ModInverse: Sized {
+    fn mod_inverse(self, modulus: Self) -> Option<Self>;
+}
+
+// The extended Euclidean algorithm on non-negative numbers, keeping the coefficient of `value` reduced modulo `modulus`
+fn mod_inverse_u128(value: u128, modulus: u128) -> Option<u128> {
+    if modulus == 0 {
+        return None;
+    }
+    let (mut old_r, mut r) = (modulus, value % modulus);
+    let (mut old_t, mut t) = (0u128, 1 % modulus);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        let product = mul_mod(quotient % modulus, t, modulus);
+        (old_t, t) = (t, if old_t >= product { old_t - product } else { modulus - (product - old_t) });
+    }
+    if old_r != 1 {
+        return None;
+    }
+    return Some(old_t);
+}
+
+macro_rules! impl_mod_inverse {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn mod_inverse(self, modulus: Self) -> Option<Self> {
+                let modulus = modulus.unsigned_abs() as u128;
+                if modulus == 0 {
+                    return None;
+                }
+                let value = match self < 0 {
+                    true => (modulus - self.unsigned_abs() as u128 % modulus) % modulus,
+                    false => self as u128,
+                };
+                return mod_inverse_u128(value, modulus).map(|x| x as $t);
+            }
+        }
+    )*)
+}
+
+macro_rules! impl_unsigned_mod_inverse {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn mod_inverse(self, modulus: Self) -> Option<Self> {
+                return mod_inverse_u128(self as u128, modulus as u128).map(|x| x as $t);
+            }
+        }
+    )*)
+}
+
+impl_mod_inverse!(ModInverse for i8 i16 i32 i64 i128 isize);
+impl_unsigned_mod_inverse!(ModInverse for u8 u16 u32 u64 u128 usize);
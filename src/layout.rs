@@ -0,0 +1,352 @@
+/*!
+# Layout
+Text rendering for vectors and matrices
+
+# Examples
+```rust
+use lemonmath::layout::{Alignment, Layout};
+use lemonmath::vectors::Vector;
+use lemonmath::matrices::Matrix;
+
+let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+let x = Vector::new(vec![5, 6], true);
+
+// Render several vectors and matrices side by side
+assert_eq!(Layout::default().side_by_side(&[&a, &x]), "⎡ 1 2 ⎤ ⎡ 5 ⎤\n⎣ 3 4 ⎦ ⎣ 6 ⎦");
+
+// Line up the decimal points of a column
+let y = Vector::new(vec![1.5, 10.25, -3.0], true);
+let layout = Layout { alignment: Alignment::Decimal, ..Layout::default() };
+
+assert_eq!(layout.render(&y), "⎡  1.5  ⎤\n⎢ 10.25 ⎥\n⎣ -3    ⎦");
+
+// Fall back to ASCII brackets for terminals without box-drawing glyphs
+let layout = Layout { ascii: true, ..Layout::default() };
+
+assert_eq!(layout.render(&x), "/ 5 \\\n\\ 6 /");
+
+// Augmented matrices, labels and truncation
+let b = Vector::new(vec![7, 8], true);
+
+assert_eq!(Layout::default().augmented(&a, &b), "⎡ 1 2 │ 7 ⎤\n⎣ 3 4 │ 8 ⎦");
+assert_eq!(Layout::default().labeled(&a, &["x", "y"], &["a", "b"]), "    a b\nx ⎡ 1 2 ⎤\ny ⎣ 3 4 ⎦");
+
+let big = Matrix::new(4, 4, (1..=16).collect());
+let layout = Layout { edge_items: Some(1), ..Layout::default() };
+
+assert_eq!(layout.render(&big), "⎡  1 …  4 ⎤\n⎢  ⋮ ⋱  ⋮ ⎥\n⎣ 13 … 16 ⎦");
+```
+*/
+
+use std::fmt::Display;
+
+use crate::matrices::Matrix;
+use crate::vectors::Vector;
+
+/// Something that can be laid out as a grid of cells
+pub trait Render {
+    /// The cells of the grid, as a list of rows that all have the same length
+    fn cells(&self) -> Vec<Vec<String>>;
+}
+
+impl<T: Display> Render for Vector<T> {
+    fn cells(&self) -> Vec<Vec<String>> {
+        if self.is_column() {
+            return self.iter().map(|x| vec![x.to_string()]).collect();
+        }
+        return vec![self.iter().map(|x| x.to_string()).collect()];
+    }
+}
+
+impl<T: Display> Render for Matrix<T> {
+    fn cells(&self) -> Vec<Vec<String>> {
+        let mut result = Vec::with_capacity(self.rows());
+        for row in 0..self.rows() {
+            result.push((0..self.cols()).map(|col| self[(row, col)].to_string()).collect());
+        }
+        return result;
+    }
+}
+
+/// How the cells of a column are lined up
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    /// Pad cells on the right
+    Left,
+    /// Pad cells on the left
+    Right,
+    /// Line up the decimal points of the cells
+    Decimal,
+}
+
+/// Options for rendering vectors and matrices as text
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::layout::{Alignment, Layout};
+/// use lemonmath::vectors::Vector;
+/// 
+/// let x = Vector::new(vec![1, 200, 30], true);
+/// let layout = Layout { alignment: Alignment::Left, ..Layout::default() };
+/// 
+/// assert_eq!(layout.render(&x), "⎡ 1   ⎤\n⎢ 200 ⎥\n⎣ 30  ⎦");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Layout {
+    /// How the cells of each column are lined up
+    pub alignment: Alignment,
+    /// Use ASCII brackets instead of box-drawing glyphs
+    pub ascii: bool,
+    /// The number of spaces between blocks rendered side by side
+    pub spacing: usize,
+    /// When set, only this many rows and columns are kept at each end of a longer dimension, with ellipses in between
+    pub edge_items: Option<usize>,
+}
+
+impl Default for Layout {
+    /// Right aligned cells with box-drawing brackets, blocks one space apart and no truncation
+    fn default() -> Self {
+        return Layout {
+            alignment: Alignment::Right,
+            ascii: false,
+            spacing: 1,
+            edge_items: None,
+        };
+    }
+}
+
+impl Layout {
+    /// Render a vector or matrix as text
+    /// 
+    /// A single row is wrapped in square brackets, anything taller gets brackets spanning every line
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::layout::Layout;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// assert_eq!(Layout::default().render(&Vector::new(vec![7], true)), "[ 7 ]");
+    /// assert_eq!(Layout::default().render(&Vector::new(vec![7, 10], true)), "⎡  7 ⎤\n⎣ 10 ⎦");
+    /// ```
+    pub fn render(&self, item: &dyn Render) -> String {
+        return self.lines(item).join("\n");
+    }
+    /// Render a vector or matrix as a list of lines that all have the same width
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::layout::Layout;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3], true);
+    /// 
+    /// assert_eq!(Layout::default().lines(&x), vec!["⎡ 1 ⎤", "⎢ 2 ⎥", "⎣ 3 ⎦"]);
+    /// ```
+    pub fn lines(&self, item: &dyn Render) -> Vec<String> {
+        let cells = self.align(self.truncate(item.cells()));
+        return self.frame(cells.iter().map(|row| row.join(" ")).collect());
+    }
+    /// Render the augmented matrix `[A | b]`, with a vertical bar between the two blocks
+    /// 
+    /// # Panics
+    /// Panics if the two blocks don't have the same number of rows
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::layout::Layout;
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let a = Matrix::new(3, 2, vec![1, 0, 0, 1, 10, 10]);
+    /// let b = Vector::new(vec![5, 6, 7], true);
+    /// let layout = Layout { ascii: true, ..Layout::default() };
+    /// 
+    /// assert_eq!(layout.augmented(&a, &b), "/  1  0 | 5 \\\n|  0  1 | 6 |\n\\ 10 10 | 7 /");
+    /// ```
+    pub fn augmented(&self, left: &dyn Render, right: &dyn Render) -> String {
+        let (left, right) = (left.cells(), right.cells());
+        if left.len() != right.len() {
+            panic!("Both sides of an augmented matrix need the same number of rows");
+        }
+        let separator = if self.ascii { "|" } else { "│" };
+        let left = self.align(self.truncate(left));
+        let right = self.align(self.truncate(right));
+        let rows = left.iter().zip(right.iter()).map(|(x, y)| format!("{} {} {}", x.join(" "), separator, y.join(" "))).collect();
+        return self.frame(rows).join("\n");
+    }
+    /// Render a vector or matrix with a label in front of each row and above each column
+    /// 
+    /// Either list of labels can be left empty, otherwise it needs one label per row or column
+    /// 
+    /// # Panics
+    /// Panics if a non-empty list of labels doesn't match the dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::layout::Layout;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 3, vec![1, 20, 3, 4, 5, 600]);
+    /// 
+    /// assert_eq!(Layout::default().labeled(&a, &[], &["x", "y", "z"]), "  x  y   z\n⎡ 1 20   3 ⎤\n⎣ 4  5 600 ⎦");
+    /// assert_eq!(Layout::default().labeled(&a, &["first", "second"], &[]), "first  ⎡ 1 20   3 ⎤\nsecond ⎣ 4  5 600 ⎦");
+    /// ```
+    pub fn labeled(&self, item: &dyn Render, row_labels: &[&str], col_labels: &[&str]) -> String {
+        let cells = item.cells();
+        let cols = cells.first().map(|row| row.len()).unwrap_or(0);
+        if (!row_labels.is_empty() && row_labels.len() != cells.len()) || (!col_labels.is_empty() && col_labels.len() != cols) {
+            panic!("Need one label per row and per column");
+        }
+        let mut cells = self.truncate(cells);
+        if !col_labels.is_empty() {
+            let header = self.truncate(vec![col_labels.iter().map(|label| label.to_string()).collect()]);
+            cells.insert(0, header[0].clone());
+        }
+        let mut cells = self.align(cells);
+        let header = match col_labels.is_empty() {
+            true => None,
+            false => Some(cells.remove(0).join(" ")),
+        };
+        let mut lines = self.frame(cells.iter().map(|row| row.join(" ")).collect());
+        if let Some(header) = header {
+            lines.insert(0, format!("  {}", header));
+        }
+        if !row_labels.is_empty() {
+            let mut labels: Vec<String> = row_labels.iter().map(|label| label.to_string()).collect();
+            labels = elide(labels, self.edge_items, self.marks().1.to_string());
+            if !col_labels.is_empty() {
+                labels.insert(0, String::new());
+            }
+            let label_width = labels.iter().map(|label| width(label)).max().unwrap_or(0);
+            for (line, label) in lines.iter_mut().zip(labels.iter()) {
+                *line = format!("{}{} {}", label, " ".repeat(label_width - width(label)), line);
+            }
+        }
+        return lines.iter().map(|line| line.trim_end()).collect::<Vec<_>>().join("\n");
+    }
+    /// Render several vectors and matrices next to each other, centered vertically
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::layout::Layout;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3], true);
+    /// let y = Vector::new(vec![4, 5], false);
+    /// 
+    /// assert_eq!(Layout::default().side_by_side(&[&x, &y]), "⎡ 1 ⎤\n⎢ 2 ⎥ [ 4 5 ]\n⎣ 3 ⎦");
+    /// ```
+    pub fn side_by_side(&self, items: &[&dyn Render]) -> String {
+        let blocks: Vec<Vec<String>> = items.iter().map(|item| self.lines(*item)).collect();
+        let height = blocks.iter().map(|block| block.len()).max().unwrap_or(0);
+        let mut result = vec![String::new(); height];
+        for (index, block) in blocks.iter().enumerate() {
+            let width = block.first().map(|line| width(line)).unwrap_or(0);
+            let top = (height - block.len()) / 2;
+            for (line_number, line) in result.iter_mut().enumerate() {
+                if index != 0 {
+                    line.push_str(&" ".repeat(self.spacing));
+                }
+                match line_number.checked_sub(top).and_then(|x| block.get(x)) {
+                    Some(text) => line.push_str(text),
+                    None => line.push_str(&" ".repeat(width)),
+                }
+            }
+        }
+        return result.iter().map(|line| line.trim_end()).collect::<Vec<_>>().join("\n");
+    }
+
+    // Wrap already joined rows in brackets
+    fn frame(&self, rows: Vec<String>) -> Vec<String> {
+        if rows.len() <= 1 {
+            return match rows.first() {
+                Some(row) if !row.is_empty() => vec![format!("[ {} ]", row)],
+                _ => vec![String::from("[ ]")],
+            };
+        }
+        let (top, middle, bottom) = match self.ascii {
+            true => (("/", "\\"), ("|", "|"), ("\\", "/")),
+            false => (("⎡", "⎤"), ("⎢", "⎥"), ("⎣", "⎦")),
+        };
+        let mut result = Vec::with_capacity(rows.len());
+        for (index, row) in rows.iter().enumerate() {
+            let brackets = if index == 0 {
+                top
+            } else if index + 1 == rows.len() {
+                bottom
+            } else {
+                middle
+            };
+            result.push(format!("{} {} {}", brackets.0, row, brackets.1));
+        }
+        return result;
+    }
+
+    // The horizontal, vertical and diagonal ellipses
+    fn marks(&self) -> (&'static str, &'static str, &'static str) {
+        return match self.ascii {
+            true => ("...", ":", "..."),
+            false => ("…", "⋮", "⋱"),
+        };
+    }
+
+    fn truncate(&self, cells: Vec<Vec<String>>) -> Vec<Vec<String>> {
+        let (horizontal, vertical, diagonal) = self.marks();
+        let cols = cells.first().map(|row| row.len()).unwrap_or(0);
+        let cols_elided = matches!(self.edge_items, Some(n) if cols > 2 * n);
+        let cells: Vec<Vec<String>> = cells.into_iter().map(|row| elide(row, self.edge_items, horizontal.to_string())).collect();
+        let mark_row = match cells.first() {
+            Some(row) => (0..row.len()).map(|col| match cols_elided && Some(col) == self.edge_items {
+                true => diagonal.to_string(),
+                false => vertical.to_string(),
+            }).collect(),
+            None => vec![],
+        };
+        return elide(cells, self.edge_items, mark_row);
+    }
+
+    fn align(&self, mut cells: Vec<Vec<String>>) -> Vec<Vec<String>> {
+        let cols = cells.first().map(|row| row.len()).unwrap_or(0);
+        for col in 0..cols {
+            match self.alignment {
+                Alignment::Left | Alignment::Right => {
+                    let max_width = cells.iter().map(|row| width(&row[col])).max().unwrap_or(0);
+                    for row in cells.iter_mut() {
+                        let padding = " ".repeat(max_width - width(&row[col]));
+                        row[col] = match self.alignment {
+                            Alignment::Left => format!("{}{}", row[col], padding),
+                            _ => format!("{}{}", padding, row[col]),
+                        };
+                    }
+                }
+                Alignment::Decimal => {
+                    let split = |cell: &str| cell.find('.').unwrap_or(cell.len());
+                    let max_whole = cells.iter().map(|row| width(&row[col][..split(&row[col])])).max().unwrap_or(0);
+                    let max_fraction = cells.iter().map(|row| width(&row[col][split(&row[col])..])).max().unwrap_or(0);
+                    for row in cells.iter_mut() {
+                        let (whole, fraction) = row[col].split_at(split(&row[col]));
+                        row[col] = format!("{}{}{}{}", " ".repeat(max_whole - width(whole)), whole, fraction, " ".repeat(max_fraction - width(fraction)));
+                    }
+                }
+            }
+        }
+        return cells;
+    }
+}
+
+// Keep `edge_items` items at each end, replacing the middle with `mark`
+fn elide<T: Clone>(items: Vec<T>, edge_items: Option<usize>, mark: T) -> Vec<T> {
+    return match edge_items {
+        Some(n) if items.len() > 2 * n => {
+            let mut result = items[..n].to_vec();
+            result.push(mark);
+            result.extend_from_slice(&items[items.len() - n..]);
+            result
+        }
+        _ => items,
+    };
+}
+
+fn width(text: &str) -> usize {
+    return text.chars().count();
+}
@@ -1,4 +1,5 @@
 #![allow(unused_imports)]
+#![allow(clippy::needless_return)]
 
 /*!
 # LemonMath
@@ -12,9 +13,23 @@ and I wanted to create the best mathematics library I could.
 ## Features
 
 * [Matrices](https://docs.rs/lemonmath/latest/lemonmath/matrices/index.html)
+* [Multiplication](https://docs.rs/lemonmath/latest/lemonmath/multiplication/index.html)
+* [Views](https://docs.rs/lemonmath/latest/lemonmath/views/index.html)
+* [Macros](https://docs.rs/lemonmath/latest/lemonmath/macros/index.html)
+* [Decompositions](https://docs.rs/lemonmath/latest/lemonmath/decompositions/index.html)
+* [Eigen](https://docs.rs/lemonmath/latest/lemonmath/eigen/index.html)
+* [SVD](https://docs.rs/lemonmath/latest/lemonmath/svd/index.html)
+* [Functions](https://docs.rs/lemonmath/latest/lemonmath/functions/index.html)
+* [Polynomials](https://docs.rs/lemonmath/latest/lemonmath/polynomials/index.html)
+* [Normal Forms](https://docs.rs/lemonmath/latest/lemonmath/normal_forms/index.html)
 * [Vectors](https://docs.rs/lemonmath/latest/lemonmath/vectors/index.html)
 * [Fraction](https://docs.rs/lemonmath/latest/lemonmath/fraction/index.html)
 * [Helper](https://docs.rs/lemonmath/latest/lemonmath/helper/index.html)
+* [Primes](https://docs.rs/lemonmath/latest/lemonmath/primes/index.html)
+* [Modular](https://docs.rs/lemonmath/latest/lemonmath/modular/index.html)
+* [Layout](https://docs.rs/lemonmath/latest/lemonmath/layout/index.html)
+* [Sparse](https://docs.rs/lemonmath/latest/lemonmath/sparse/index.html)
+* [Solvers](https://docs.rs/lemonmath/latest/lemonmath/solvers/index.html)
 
 ## Usage
 
@@ -29,4 +44,18 @@ Currently vectors and matrices are work in progress and will be updated soon.
 pub mod matrices;
 pub mod vectors;
 pub mod helper;
-pub mod fraction;
\ No newline at end of file
+pub mod primes;
+pub mod modular;
+pub mod fraction;
+pub mod sparse;
+pub mod layout;
+pub mod decompositions;
+pub mod eigen;
+pub mod svd;
+pub mod polynomials;
+pub mod normal_forms;
+pub mod macros;
+pub mod functions;
+pub mod solvers;
+pub mod views;
+pub mod multiplication;
\ No newline at end of file
@@ -0,0 +1,271 @@
+/*!
+# Macros
+Macros for writing matrices and vectors
+
+Entries can be integers, floats, `n/d` fraction literals or any other expressions.
+As soon as one entry is an `n/d` literal, every entry becomes a [`Fraction`], including expressions.
+Only an entry that is exactly two literals around a `/` is read as a fraction, so `x / 2` is still an expression.
+Negated expressions need parentheses, as in `(-x)`.
+
+# Examples
+```rust
+use lemonmath::{matrix, vector, col, row};
+use lemonmath::matrices::Matrix;
+use lemonmath::vectors::Vector;
+use lemonmath::fraction::Fraction;
+
+// Rows are separated by semicolons
+let a = matrix![1, 2; 3, 4];
+
+assert_eq!(a, Matrix::new(2, 2, vec![1, 2, 3, 4]));
+
+// Fraction literals
+let b = matrix![1/2, 1; -1/3, 0.25];
+
+assert_eq!(b[(0, 0)], Fraction::new(1, 2));
+assert_eq!(b[(1, 0)], Fraction::new(-1, 3));
+assert_eq!(b[(1, 1)], Fraction::new(1, 4));
+
+// Mixed with expressions
+let x = 3;
+
+assert_eq!(matrix![1/2, x], Matrix::new(1, 2, vec![Fraction::new(1, 2), Fraction::new(3, 1)]));
+
+// Column and row vectors
+assert_eq!(vector![1, 2, 3], Vector::new(vec![1, 2, 3], true));
+assert_eq!(col![1.5, 2.5], Vector::new(vec![1.5, 2.5], true));
+assert_eq!(row![1/2, 3], Vector::new(vec![Fraction::new(1, 2), Fraction::new(3, 1)], false));
+```
+*/
+
+use crate::fraction::Fraction;
+
+#[test]
+fn macros_test() {
+    use crate::matrices::Matrix;
+    use crate::vectors::Vector;
+    use crate::{matrix, vector, col, row};
+
+    // A fraction literal next to an expression still makes every entry a fraction
+    let x = 3;
+    assert_eq!(matrix![1/2, x], Matrix::new(1, 2, vec![Fraction::new(1, 2), Fraction::new(3, 1)]));
+    assert_eq!(matrix![x, -1/4; 2 * x, 1.5], Matrix::new(2, 2, vec![Fraction::new(3, 1), Fraction::new(-1, 4), Fraction::new(6, 1), Fraction::new(3, 2)]));
+    assert_eq!(matrix![x, 1; 2, 1/3;], matrix![3, 1; 2, 1/3]);
+    assert_eq!(col![x, 2/3,], Vector::new(vec![Fraction::new(3, 1), Fraction::new(2, 3)], true));
+    assert_eq!(row![(-x), 5/10], Vector::new(vec![Fraction::new(-3, 1), Fraction::new(1, 2)], false));
+    assert_eq!(vector![Fraction::new(1, 5), 1/5], Vector::new(vec![Fraction::new(1, 5); 2], true));
+    let (size, offset, large) = (2usize, -1isize, 1u128 << 100);
+    assert_eq!(row![size, offset, large, 1/2], Vector::new(vec![Fraction::new(2, 1), Fraction::new(-1, 1), Fraction::new(1 << 100, 1), Fraction::new(1, 2)], false));
+
+    // Without a fraction literal, expressions keep their own type
+    assert_eq!(matrix![x / 2, (-x); x * 2, 1], Matrix::new(2, 2, vec![1, -3, 6, 1]));
+    assert_eq!(row![x, x * x], Vector::new(vec![3, 9], false));
+}
+
+/// Turns a single macro entry into a fraction
+#[doc(hidden)]
+pub trait IntoFraction {
+    fn into_fraction(self) -> Fraction;
+}
+
+macro_rules! impl_into_fraction {
+    ($name:ident for $($t:ty)*) => ($(
+        impl $name for $t {
+            fn into_fraction(self) -> Fraction {
+                return Fraction::from(self);
+            }
+        }
+    )*)
+}
+
+impl_into_fraction!(IntoFraction for u8 u16 u32 u64 usize i8 i16 i32 i64 i128 isize);
+
+impl IntoFraction for u128 {
+    fn into_fraction(self) -> Fraction {
+        match Fraction::try_from(self) {
+            Ok(result) => return result,
+            Err(_) => panic!("{} doesn't fit in a fraction", self),
+        }
+    }
+}
+
+impl IntoFraction for f64 {
+    fn into_fraction(self) -> Fraction {
+        return Fraction::from_float(self);
+    }
+}
+
+impl IntoFraction for f32 {
+    fn into_fraction(self) -> Fraction {
+        return Fraction::from_float(self as f64);
+    }
+}
+
+impl IntoFraction for Fraction {
+    fn into_fraction(self) -> Fraction {
+        return self;
+    }
+}
+
+/// Turns a single macro entry into a fraction, reading `n/d` as an exact fraction
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fraction_entry {
+    ($n:literal / $d:literal) => {
+        $crate::fraction::Fraction::new($n, $d)
+    };
+    ($x:expr) => {
+        $crate::macros::IntoFraction::into_fraction($x)
+    };
+}
+
+/// Splits macro entries that aren't all literals one at a time, remembering whether any of them is an `n/d` literal.
+/// The rows are collected as `[{entry} ...]` and the marker becomes `[fraction]` after the first fraction literal
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __macro_entries {
+    ($kind:ident [$($rows:tt)*] [$($row:tt)*] [$($fraction:ident)?] $n:literal / $d:literal , $($rest:tt)*) => {
+        $crate::__macro_entries!($kind [$($rows)*] [$($row)* {$n / $d}] [fraction] $($rest)*)
+    };
+    ($kind:ident [$($rows:tt)*] [$($row:tt)*] [$($fraction:ident)?] $n:literal / $d:literal ; $($rest:tt)*) => {
+        $crate::__macro_entries!($kind [$($rows)* [$($row)* {$n / $d}]] [] [fraction] $($rest)*)
+    };
+    ($kind:ident [$($rows:tt)*] [$($row:tt)*] [$($fraction:ident)?] $n:literal / $d:literal) => {
+        $crate::__macro_entries!($kind [$($rows)*] [$($row)* {$n / $d}] [fraction])
+    };
+    ($kind:ident [$($rows:tt)*] [$($row:tt)*] [$($fraction:ident)?] $x:expr , $($rest:tt)*) => {
+        $crate::__macro_entries!($kind [$($rows)*] [$($row)* {$x}] [$($fraction)?] $($rest)*)
+    };
+    ($kind:ident [$($rows:tt)*] [$($row:tt)*] [$($fraction:ident)?] $x:expr ; $($rest:tt)*) => {
+        $crate::__macro_entries!($kind [$($rows)* [$($row)* {$x}]] [] [$($fraction)?] $($rest)*)
+    };
+    ($kind:ident [$($rows:tt)*] [$($row:tt)*] [$($fraction:ident)?] $x:expr) => {
+        $crate::__macro_entries!($kind [$($rows)*] [$($row)* {$x}] [$($fraction)?])
+    };
+    ($kind:ident [$($rows:tt)*] [$($row:tt)+] [$($fraction:ident)?]) => {
+        $crate::__macro_entries!($kind [$($rows)* [$($row)+]] [] [$($fraction)?])
+    };
+    (matrix [$([$({$($entry:tt)*})+])+] [] [fraction]) => {
+        $crate::matrices::Matrix::from_rows(vec![$($crate::vectors::Vector::new(vec![$($crate::__fraction_entry!($($entry)*)),+], false)),+])
+    };
+    (matrix [$([$({$($entry:tt)*})+])+] [] []) => {
+        $crate::matrices::Matrix::from_rows(vec![$($crate::vectors::Vector::new(vec![$($($entry)*),+], false)),+])
+    };
+    ($kind:ident [[$({$($entry:tt)*})+]] [] [fraction]) => {
+        $crate::vectors::Vector::new(vec![$($crate::__fraction_entry!($($entry)*)),+], $crate::__macro_entries!(@column $kind))
+    };
+    ($kind:ident [[$({$($entry:tt)*})+]] [] []) => {
+        $crate::vectors::Vector::new(vec![$($($entry)*),+], $crate::__macro_entries!(@column $kind))
+    };
+    (@column col) => {
+        true
+    };
+    (@column row) => {
+        false
+    };
+}
+
+/// Create a matrix from its rows, separated by semicolons
+/// 
+/// # Panics
+/// Panics if the rows don't all have the same length
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::matrix;
+/// use lemonmath::matrices::Matrix;
+/// use lemonmath::fraction::Fraction;
+/// 
+/// let x = 5;
+/// 
+/// assert_eq!(matrix![1, 2, 3; x, x + 1, 7], Matrix::new(2, 3, vec![1, 2, 3, 5, 6, 7]));
+/// assert_eq!(matrix![1/2; 2], Matrix::new(2, 1, vec![Fraction::new(1, 2), Fraction::new(2, 1)]));
+/// assert_eq!(matrix![], Matrix::<i32>::new(0, 0, vec![]));
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    () => {
+        $crate::matrices::Matrix::new(0, 0, vec![])
+    };
+    ($($($n:literal),+);+ $(;)?) => {
+        $crate::matrices::Matrix::from_rows(vec![$($crate::vectors::Vector::new(vec![$($n),+], false)),+])
+    };
+    ($($($n:literal $(/ $d:literal)?),+);+ $(;)?) => {
+        $crate::matrices::Matrix::from_rows(vec![$($crate::vectors::Vector::new(vec![$($crate::__fraction_entry!($n $(/ $d)?)),+], false)),+])
+    };
+    ($($tokens:tt)+) => {
+        $crate::__macro_entries!(matrix [] [] [] $($tokens)+)
+    };
+}
+
+/// Create a column vector
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::vector;
+/// use lemonmath::vectors::Vector;
+/// use lemonmath::fraction::Fraction;
+/// 
+/// assert_eq!(vector![1, 2], Vector::new(vec![1, 2], true));
+/// assert_eq!(vector![1/3, 2], Vector::new(vec![Fraction::new(1, 3), Fraction::new(2, 1)], true));
+/// ```
+#[macro_export]
+macro_rules! vector {
+    ($($tokens:tt)*) => {
+        $crate::col![$($tokens)*]
+    };
+}
+
+/// Create a column vector
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::col;
+/// use lemonmath::vectors::Vector;
+/// 
+/// let x = 2.0;
+/// 
+/// assert_eq!(col![1.0, x * 2.0], Vector::new(vec![1.0, 4.0], true));
+/// ```
+#[macro_export]
+macro_rules! col {
+    () => {
+        $crate::vectors::Vector::new(vec![], true)
+    };
+    ($($n:literal),+ $(,)?) => {
+        $crate::vectors::Vector::new(vec![$($n),+], true)
+    };
+    ($($n:literal $(/ $d:literal)?),+ $(,)?) => {
+        $crate::vectors::Vector::new(vec![$($crate::__fraction_entry!($n $(/ $d)?)),+], true)
+    };
+    ($($tokens:tt)+) => {
+        $crate::__macro_entries!(col [] [] [] $($tokens)+)
+    };
+}
+
+/// Create a row vector
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::row;
+/// use lemonmath::vectors::Vector;
+/// use lemonmath::fraction::Fraction;
+/// 
+/// assert_eq!(row![1, 2], Vector::new(vec![1, 2], false));
+/// assert_eq!(row![-1/2, 1/4], Vector::new(vec![Fraction::new(-1, 2), Fraction::new(1, 4)], false));
+/// ```
+#[macro_export]
+macro_rules! row {
+    () => {
+        $crate::vectors::Vector::new(vec![], false)
+    };
+    ($($n:literal),+ $(,)?) => {
+        $crate::vectors::Vector::new(vec![$($n),+], false)
+    };
+    ($($n:literal $(/ $d:literal)?),+ $(,)?) => {
+        $crate::vectors::Vector::new(vec![$($crate::__fraction_entry!($n $(/ $d)?)),+], false)
+    };
+    ($($tokens:tt)+) => {
+        $crate::__macro_entries!(row [] [] [] $($tokens)+)
+    };
+}
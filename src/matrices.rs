@@ -1,15 +1,868 @@
-// use crate::vectors::Vector;
+/*!
+# Matrices
+Math Matrices
 
-// pub struct Matrix<T> {
-//     content: Vec<Vector<T>>
-// }
+# Examples
+```rust
+use lemonmath::matrices::Matrix;
 
-// impl<T> Matrix<T> {
-//     pub fn new(content: Vec<Vector<T>>) -> Self {
-//         return Matrix {
-//             content
-//         }
-//     }
+// Create a Matrix from its dimensions and its elements in row order
+let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
 
+assert_eq!(x.rows(), 2);
+assert_eq!(x.cols(), 3);
 
-// }
\ No newline at end of file
+// Index a Matrix with a (row, column) pair
+assert_eq!(x[(1, 2)], 6);
+
+// Display Trait
+assert_eq!(format!("{}", x), "⎡ 1 2 3 ⎤\n⎣ 4 5 6 ⎦");
+```
+*/
+
+use std::{fmt::Display, error::Error, ops::{Index, IndexMut, Add, AddAssign, Sub, Mul, Div, Neg, Range}};
+
+use crate::vectors::Vector;
+use crate::layout::Layout;
+use crate::multiplication::multiply_blocked;
+
+#[test]
+fn matrices_test() {
+    // Integer minors and adjugates don't go through any truncating division
+    let x = Matrix::new(3, 3, vec![2i64, 1, 0, 3, 4, 1, 0, 1, 5]);
+    assert_eq!(x.minor(0, 0), Ok(19));
+    assert_eq!(x.minor(1, 1), Ok(10));
+    assert_eq!(x.cofactor(0, 1), Ok(-15));
+    let adjugate = x.adjugate().unwrap();
+    assert_eq!(adjugate, Matrix::new(3, 3, vec![19, -5, 1, -15, 10, -2, 3, -2, 5]));
+    assert_eq!(x * adjugate, Matrix::new(3, 3, vec![23, 0, 0, 0, 23, 0, 0, 0, 23]));
+
+    // A zero pivot is swapped out instead of dividing by it
+    let x = Matrix::new(4, 4, vec![1i128, 0, 1, 2, 0, 0, 3, 1, 0, 2, 1, 1, 4, 1, 0, 3]);
+    assert_eq!(x.minor(0, 0), Ok(-16));
+    assert_eq!(x.minor(3, 3), Ok(-6));
+    assert_eq!(Matrix::new(3, 3, vec![1, 2, 3, 2, 4, 6, 0, 1, 1]).minor(2, 2), Ok(0));
+}
+
+/// Math Matrix
+#[derive(Clone, PartialEq, Debug)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    content: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    /// Create the Matrix from its dimensions and its elements in row order
+    /// 
+    /// # Panics
+    /// Panics if `content` doesn't have exactly `rows * cols` elements
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x[(0, 1)], 2);
+    /// assert_eq!(x[(1, 0)], 3);
+    /// ```
+    pub fn new(rows: usize, cols: usize, content: Vec<T>) -> Self {
+        if content.len() != rows * cols {
+            panic!("A {}x{} matrix needs {} elements, got {}", rows, cols, rows * cols, content.len());
+        }
+        return Matrix {
+            rows,
+            cols,
+            content
+        };
+    }
+    /// Create a matrix by calling `f(row, col)` for every element
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::from_fn(2, 3, |row, col| row * 10 + col);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 3, vec![0, 1, 2, 10, 11, 12]));
+    /// ```
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> Self {
+        let mut content = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                content.push(f(row, col));
+            }
+        }
+        return Matrix::new(rows, cols, content);
+    }
+    /// Create a matrix from its rows, whatever the orientation of the vectors
+    /// 
+    /// # Panics
+    /// Panics if the rows don't all have the same length
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::from_rows(vec![Vector::new(vec![1, 2], false), Vector::new(vec![3, 4], false)]);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![1, 2, 3, 4]));
+    /// ```
+    pub fn from_rows(rows: Vec<Vector<T>>) -> Self {
+        let cols = rows.first().map(|row| row.len()).unwrap_or(0);
+        let count = rows.len();
+        let mut content = Vec::with_capacity(count * cols);
+        for row in rows {
+            if row.len() != cols {
+                panic!("Every row needs {} elements, got {}", cols, row.len());
+            }
+            content.extend(row.content);
+        }
+        return Matrix::new(count, cols, content);
+    }
+    /// The number of rows in the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.rows(), 2);
+    /// ```
+    pub fn rows(&self) -> usize {
+        return self.rows;
+    }
+    /// The number of columns in the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.cols(), 3);
+    /// ```
+    pub fn cols(&self) -> usize {
+        return self.cols;
+    }
+    /// Get an element of the matrix, or `None` if it's out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.get(1, 1), Some(&4));
+    /// assert_eq!(x.get(2, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        return self.content.get(row * self.cols + col);
+    }
+    /// The elements of the matrix in row order
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.content(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn content(&self) -> &[T] {
+        return &self.content;
+    }
+    /// The elements of the matrix in row order, mutably
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// x.content_mut()[3] = 5;
+    /// 
+    /// assert_eq!(x[(1, 1)], 5);
+    /// ```
+    pub fn content_mut(&mut self) -> &mut [T] {
+        return &mut self.content;
+    }
+    /// Check if the matrix has as many rows as columns
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// assert!(Matrix::new(2, 2, vec![1, 2, 3, 4]).is_square());
+    /// assert!(!Matrix::new(1, 2, vec![1, 2]).is_square());
+    /// ```
+    pub fn is_square(&self) -> bool {
+        return self.rows == self.cols;
+    }
+    /// Swap two rows of the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// x.swap_rows(0, 1);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![3, 4, 1, 2]));
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        for col in 0..self.cols {
+            self.content.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Get a copy of a row of the matrix as a row vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.row(1), Vector::new(vec![3, 4], false));
+    /// ```
+    pub fn row(&self, row: usize) -> Vector<T> {
+        return Vector::new(self.content[row * self.cols..(row + 1) * self.cols].to_vec(), false);
+    }
+    /// Get a copy of a column of the matrix as a column vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.col(1), Vector::new(vec![2, 4], true));
+    /// ```
+    pub fn col(&self, col: usize) -> Vector<T> {
+        return Vector::new((0..self.rows).map(|row| self[(row, col)].clone()).collect(), true);
+    }
+    /// Create a matrix from its columns, whatever the orientation of the vectors
+    /// 
+    /// # Panics
+    /// Panics if the columns don't all have the same length
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::from_cols(vec![Vector::new(vec![1, 2], true), Vector::new(vec![3, 4], true)]);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![1, 3, 2, 4]));
+    /// ```
+    pub fn from_cols(cols: Vec<Vector<T>>) -> Self {
+        return Matrix::from_rows(cols).transpose();
+    }
+    /// Assemble a matrix from a grid of blocks
+    /// 
+    /// # Panics
+    /// Panics if the blocks in a row of the grid don't have the same height,
+    /// or the blocks in a column of the grid don't have the same width
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let b = Matrix::new(2, 1, vec![5, 6]);
+    /// let c = Matrix::new(1, 2, vec![7, 8]);
+    /// let d = Matrix::new(1, 1, vec![9]);
+    /// 
+    /// let x = Matrix::block(vec![vec![a, b], vec![c, d]]);
+    /// 
+    /// assert_eq!(x, Matrix::new(3, 3, vec![1, 2, 5, 3, 4, 6, 7, 8, 9]));
+    /// ```
+    pub fn block(blocks: Vec<Vec<Matrix<T>>>) -> Self {
+        let widths: Vec<usize> = blocks.first().map(|row| row.iter().map(|block| block.cols).collect()).unwrap_or_default();
+        let mut content = vec![];
+        let mut rows = 0;
+        for block_row in &blocks {
+            let height = block_row.first().map(|block| block.rows).unwrap_or(0);
+            if block_row.len() != widths.len() {
+                panic!("Every row of blocks needs {} blocks, got {}", widths.len(), block_row.len());
+            }
+            for (block, width) in block_row.iter().zip(widths.iter()) {
+                if block.rows != height || block.cols != *width {
+                    panic!("A {}x{} block doesn't fit in a {}x{} slot", block.rows, block.cols, height, width);
+                }
+            }
+            for row in 0..height {
+                for block in block_row {
+                    content.extend_from_slice(&block.content[row * block.cols..(row + 1) * block.cols]);
+                }
+            }
+            rows += height;
+        }
+        return Matrix::new(rows, widths.iter().sum(), content);
+    }
+    /// The transpose of the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.transpose(), Matrix::new(3, 2, vec![1, 4, 2, 5, 3, 6]));
+    /// ```
+    pub fn transpose(&self) -> Self {
+        let mut content = Vec::with_capacity(self.content.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                content.push(self[(row, col)].clone());
+            }
+        }
+        return Matrix::new(self.cols, self.rows, content);
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Join two matrices side by side
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if the matrices have a different number of rows
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 1, vec![1, 2]);
+    /// let y = Matrix::new(2, 2, vec![3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.hstack(&y), Ok(Matrix::new(2, 3, vec![1, 3, 4, 2, 5, 6])));
+    /// ```
+    pub fn hstack(&self, other: &Self) -> Result<Self, MatrixError> {
+        if self.rows != other.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        return Ok(Matrix::block(vec![vec![self.clone(), other.clone()]]));
+    }
+    /// Stack two matrices on top of each other
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if the matrices have a different number of columns
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(1, 2, vec![1, 2]);
+    /// let y = Matrix::new(2, 2, vec![3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.vstack(&y), Ok(Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6])));
+    /// ```
+    pub fn vstack(&self, other: &Self) -> Result<Self, MatrixError> {
+        if self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let mut content = self.content.clone();
+        content.extend_from_slice(&other.content);
+        return Ok(Matrix::new(self.rows + other.rows, self.cols, content));
+    }
+    /// Copy the block of the matrix covered by a range of rows and a range of columns
+    /// 
+    /// # Panics
+    /// Panics if a range is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// 
+    /// assert_eq!(x.submatrix(1..3, 0..2), Matrix::new(2, 2, vec![4, 5, 7, 8]));
+    /// ```
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Self {
+        if rows.end > self.rows || cols.end > self.cols {
+            panic!("The block {:?}x{:?} is out of bounds for a {}x{} matrix", rows, cols, self.rows, self.cols);
+        }
+        let mut content = Vec::with_capacity(rows.len() * cols.len());
+        for row in rows.clone() {
+            content.extend_from_slice(&self.content[row * self.cols + cols.start..row * self.cols + cols.end]);
+        }
+        return Matrix::new(rows.len(), cols.len(), content);
+    }
+    /// Overwrite the block of the matrix starting at `(row, col)` with another matrix
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if the block doesn't fit
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::zeros(3, 3);
+    /// 
+    /// x.set_block(1, 1, &Matrix::new(2, 2, vec![1, 2, 3, 4])).unwrap();
+    /// 
+    /// assert_eq!(x, Matrix::new(3, 3, vec![0, 0, 0, 0, 1, 2, 0, 3, 4]));
+    /// assert!(x.set_block(2, 2, &Matrix::new(2, 2, vec![1, 2, 3, 4])).is_err());
+    /// ```
+    pub fn set_block(&mut self, row: usize, col: usize, block: &Self) -> Result<(), MatrixError> {
+        if row + block.rows > self.rows || col + block.cols > self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        for x in 0..block.rows {
+            let start = (row + x) * self.cols + col;
+            self.content[start..start + block.cols].clone_from_slice(&block.content[x * block.cols..(x + 1) * block.cols]);
+        }
+        return Ok(());
+    }
+
+    // The matrix without one of its rows and one of its columns
+    fn without(&self, row: usize, col: usize) -> Self {
+        let mut content = Vec::with_capacity((self.rows - 1) * (self.cols - 1));
+        for x in (0..self.rows).filter(|x| *x != row) {
+            for y in (0..self.cols).filter(|y| *y != col) {
+                content.push(self[(x, y)].clone());
+            }
+        }
+        return Matrix::new(self.rows - 1, self.cols - 1, content);
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Matrix<T> {
+    /// The elementwise (Hadamard) product of two matrices
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if the matrices have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let y = Matrix::new(2, 2, vec![5, 6, 7, 8]);
+    /// 
+    /// assert_eq!(x.hadamard(&y), Ok(Matrix::new(2, 2, vec![5, 12, 21, 32])));
+    /// ```
+    pub fn hadamard(&self, other: &Self) -> Result<Self, MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let content = self.content.iter().zip(other.content.iter()).map(|(a, b)| *a * *b).collect();
+        return Ok(Matrix::new(self.rows, self.cols, content));
+    }
+    /// The Kronecker product, where every element of `self` is replaced by that element times `other`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(1, 2, vec![1, 2]);
+    /// let y = Matrix::new(2, 2, vec![1, 0, 0, 1]);
+    /// 
+    /// assert_eq!(x.kronecker(&y), Matrix::new(2, 4, vec![1, 0, 2, 0, 0, 1, 0, 2]));
+    /// ```
+    pub fn kronecker(&self, other: &Self) -> Self {
+        let (rows, cols) = (self.rows * other.rows, self.cols * other.cols);
+        return Matrix::from_fn(rows, cols, |row, col| {
+            self[(row / other.rows, col / other.cols)] * other[(row % other.rows, col % other.cols)]
+        });
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Neg<Output = T> + PartialEq + Default + From<u8> + Copy,
+{
+    /// The determinant of the matrix without row `row` and column `col`
+    /// 
+    /// It's found with fraction-free (Bareiss) elimination, where every division is exact, so integer matrices give exact results
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// 
+    /// # Panics
+    /// Panics if the position is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0]);
+    /// 
+    /// // The determinant of [[5, 6], [8, 10]]
+    /// assert_eq!(x.minor(0, 0), Ok(2.0));
+    /// ```
+    pub fn minor(&self, row: usize, col: usize) -> Result<T, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        if row >= self.rows || col >= self.cols {
+            panic!("Position ({}, {}) is out of bounds for a {}x{} matrix", row, col, self.rows, self.cols);
+        }
+        return Ok(bareiss(self.without(row, col)));
+    }
+    /// The signed minor `(-1)^(row + col) * minor(row, col)`
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// 
+    /// # Panics
+    /// Panics if the position is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::fraction::Fraction;
+    /// use lemonmath::helper::VecToFraction;
+    /// 
+    /// let x = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 10].to_fraction());
+    /// 
+    /// // Minus the determinant of [[4, 6], [7, 10]]
+    /// assert_eq!(x.cofactor(0, 1), Ok(Fraction::new(2, 1)));
+    /// ```
+    pub fn cofactor(&self, row: usize, col: usize) -> Result<T, MatrixError> {
+        let minor = self.minor(row, col)?;
+        if (row + col) % 2 == 1 {
+            return Ok(-minor);
+        }
+        return Ok(minor);
+    }
+    /// The adjugate, the transpose of the matrix of cofactors
+    /// 
+    /// Dividing it by the determinant gives the inverse, which is exact for fraction matrices.
+    /// The cofactors themselves are exact for integer matrices too
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::helper::VecToFraction;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![2, 1, 7, 4].to_fraction());
+    /// 
+    /// let adjugate = x.adjugate().unwrap();
+    /// 
+    /// assert_eq!(adjugate, Matrix::new(2, 2, vec![4, -1, -7, 2].to_fraction()));
+    /// 
+    /// // The determinant is 1, so the adjugate is the inverse
+    /// assert_eq!(x * adjugate, Matrix::identity(2));
+    /// ```
+    pub fn adjugate(&self) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        if self.rows == 1 {
+            return Ok(Matrix::identity(1));
+        }
+        let mut result = Matrix::zeros(self.rows, self.cols);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                result[(col, row)] = self.cofactor(row, col)?;
+            }
+        }
+        return Ok(result);
+    }
+}
+
+impl<T: Default + Clone> Matrix<T> {
+    /// Create a matrix filled with zeros
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// assert_eq!(Matrix::zeros(2, 1), Matrix::new(2, 1, vec![0, 0]));
+    /// ```
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        return Matrix::new(rows, cols, vec![T::default(); rows * cols]);
+    }
+    /// Create a square matrix with the given values on its diagonal
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// assert_eq!(Matrix::diag(vec![1, 2]), Matrix::new(2, 2, vec![1, 0, 0, 2]));
+    /// ```
+    pub fn diag(values: Vec<T>) -> Self {
+        let size = values.len();
+        let mut result = Matrix::zeros(size, size);
+        for (x, value) in values.into_iter().enumerate() {
+            result[(x, x)] = value;
+        }
+        return result;
+    }
+}
+
+impl<T: Default + Clone + From<u8>> Matrix<T> {
+    /// Create the identity matrix of the given size
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// assert_eq!(Matrix::<f64>::identity(2), Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 1.0]));
+    /// ```
+    pub fn identity(size: usize) -> Self {
+        let mut result = Matrix::zeros(size, size);
+        for x in 0..size {
+            result[(x, x)] = T::from(1u8);
+        }
+        return result;
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// Index the matrix with a (row, column) pair
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x[(1, 0)], 3);
+    /// ```
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        if index.0 >= self.rows || index.1 >= self.cols {
+            panic!("Index ({}, {}) is out of bounds for a {}x{} matrix", index.0, index.1, self.rows, self.cols);
+        }
+        return &self.content[index.0 * self.cols + index.1];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    /// Mutably index the matrix with a (row, column) pair
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// x[(1, 0)] = 7;
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![1, 2, 7, 4]));
+    /// ```
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        if index.0 >= self.rows || index.1 >= self.cols {
+            panic!("Index ({}, {}) is out of bounds for a {}x{} matrix", index.0, index.1, self.rows, self.cols);
+        }
+        return &mut self.content[index.0 * self.cols + index.1];
+    }
+}
+
+
+impl<T: Display> Display for Matrix<T> {
+    /// Display the matrix
+    /// 
+    /// Columns are right aligned to their widest entry, use [`Layout`] for other alignments, labels, truncation or ASCII brackets
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(3, 2, vec![1, -20, 300, 4, 5, 6]);
+    /// 
+    /// assert_eq!(format!("{}", x), "⎡   1 -20 ⎤\n⎢ 300   4 ⎥\n⎣   5   6 ⎦");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(&Layout::default().render(self));
+    }
+}
+
+impl<T: Add<Output = T> + Copy> Add for Matrix<T> {
+    type Output = Self;
+
+    /// Add two matrices
+    /// 
+    /// # Panics
+    /// Panics if the matrices have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let y = Matrix::new(2, 2, vec![4, 3, 2, 1]);
+    /// 
+    /// assert_eq!(x + y, Matrix::new(2, 2, vec![5, 5, 5, 5]));
+    /// ```
+    fn add(self, other: Self) -> Self {
+        if self.rows != other.rows || self.cols != other.cols {
+            panic!("Matrices must have the same dimensions");
+        }
+        let content = self.content.iter().zip(other.content.iter()).map(|(a, b)| *a + *b).collect();
+        return Matrix::new(self.rows, self.cols, content);
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> Sub for Matrix<T> {
+    type Output = Self;
+
+    /// Subtract two matrices
+    /// 
+    /// # Panics
+    /// Panics if the matrices have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let y = Matrix::new(2, 2, vec![4, 3, 2, 1]);
+    /// 
+    /// assert_eq!(x - y, Matrix::new(2, 2, vec![-3, -1, 1, 3]));
+    /// ```
+    fn sub(self, other: Self) -> Self {
+        if self.rows != other.rows || self.cols != other.cols {
+            panic!("Matrices must have the same dimensions");
+        }
+        let content = self.content.iter().zip(other.content.iter()).map(|(a, b)| *a - *b).collect();
+        return Matrix::new(self.rows, self.cols, content);
+    }
+}
+
+impl<T: AddAssign + Default + Mul<Output = T> + Copy> Mul for Matrix<T> {
+    type Output = Self;
+
+    /// Multiply two matrices, using the cache-blocked kernel from [`crate::multiplication`]
+    /// 
+    /// # Panics
+    /// Panics if `self` doesn't have as many columns as `other` has rows
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let y = Matrix::new(3, 1, vec![1, 0, -1]);
+    /// 
+    /// assert_eq!(x * y, Matrix::new(2, 1, vec![-2, -2]));
+    /// ```
+    fn mul(self, other: Self) -> Self {
+        if self.cols != other.rows {
+            panic!("Can't multiply a {}x{} matrix by a {}x{} matrix", self.rows, self.cols, other.rows, other.cols);
+        }
+        return multiply_blocked(&self, &other);
+    }
+}
+
+impl<T: AddAssign + Default + Mul<Output = T> + Copy> Mul<Vector<T>> for Matrix<T> {
+    type Output = Vector<T>;
+
+    /// Multiply a matrix by a column vector
+    /// 
+    /// # Panics
+    /// Panics if the vector isn't a column, or doesn't have an element for every column of the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let y = Vector::new(vec![1, 1], true);
+    /// 
+    /// assert_eq!(x * y, Vector::new(vec![3, 7], true));
+    /// ```
+    fn mul(self, other: Vector<T>) -> Vector<T> {
+        if !other.is_column() || other.len() != self.cols {
+            panic!("Can only multiply a {}x{} matrix by a column vector of length {}", self.rows, self.cols, self.cols);
+        }
+        let mut result = Vector::new(vec![T::default(); self.rows], true);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                result[row] += self[(row, col)] * other[col];
+            }
+        }
+        return result;
+    }
+}
+
+/// The errors returned by matrix algorithms
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatrixError {
+    /// The algorithm needs a square matrix
+    NotSquare,
+    /// The dimensions of the operands don't match
+    DimensionMismatch,
+    /// The matrix is singular
+    Singular,
+    /// The algorithm needs a symmetric matrix
+    NotSymmetric,
+    /// The algorithm needs a positive-definite matrix
+    NotPositiveDefinite,
+    /// The columns of the matrix are linearly dependent
+    RankDeficient,
+    /// An iterative algorithm didn't converge within its iteration limit
+    NoConvergence,
+    /// An intermediate value doesn't fit in the element type
+    Overflow,
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            MatrixError::NotSquare => "the matrix is not square",
+            MatrixError::DimensionMismatch => "the dimensions don't match",
+            MatrixError::Singular => "the matrix is singular",
+            MatrixError::NotSymmetric => "the matrix is not symmetric",
+            MatrixError::NotPositiveDefinite => "the matrix is not positive-definite",
+            MatrixError::RankDeficient => "the columns of the matrix are linearly dependent",
+            MatrixError::NoConvergence => "the algorithm did not converge",
+            MatrixError::Overflow => "an intermediate value overflowed",
+        };
+        return f.write_str(message);
+    }
+}
+
+impl Error for MatrixError {}
+
+// The determinant by fraction-free (Bareiss) elimination, where each division by the previous pivot is exact
+fn bareiss<T>(mut matrix: Matrix<T>) -> T
+where
+    T: Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Neg<Output = T> + PartialEq + Default + From<u8> + Copy,
+{
+    let size = matrix.rows;
+    let mut previous = T::from(1u8);
+    let mut negative = false;
+    for k in 0..size {
+        if matrix[(k, k)] == T::default() {
+            match (k + 1..size).find(|row| matrix[(*row, k)] != T::default()) {
+                Some(row) => {
+                    matrix.swap_rows(row, k);
+                    negative = !negative;
+                }
+                None => return T::default(),
+            }
+        }
+        for row in k + 1..size {
+            for col in k + 1..size {
+                matrix[(row, col)] = (matrix[(row, col)] * matrix[(k, k)] - matrix[(row, k)] * matrix[(k, col)]) / previous;
+            }
+        }
+        previous = matrix[(k, k)];
+    }
+    if negative {
+        return -previous;
+    }
+    return previous;
+}
@@ -0,0 +1,487 @@
+/*!
+# Modular
+Modular arithmetic, with the modulus fixed at compile time or chosen at runtime, and the Chinese remainder theorem
+
+Multiplication uses Montgomery reduction for odd moduli, so no 128 bit division is needed.
+Values are kept in Montgomery form internally, but every method works with ordinary residues.
+
+# Examples
+```rust
+use lemonmath::modular::{ModInt, DynModInt, crt};
+use lemonmath::fraction::Fraction;
+
+type Mod7 = ModInt<7>;
+
+let x = Mod7::new(3);
+let y = Mod7::new(5);
+
+assert_eq!((x + y).value(), 1);
+assert_eq!((x * y).value(), 1);
+assert_eq!((x / y).value(), 2);
+assert_eq!(x.pow(6).value(), 1);
+
+// The same with a modulus chosen at runtime
+let p = 1_000_000_007;
+let z = DynModInt::new(2, p);
+
+assert_eq!(z.inverse().unwrap().value(), 500_000_004);
+
+// Fractions modulo a prime
+assert_eq!(Fraction::new(1, 2).to_mod(p), Some(z.inverse().unwrap()));
+
+// x ≡ 2 (mod 3), x ≡ 3 (mod 5) and x ≡ 2 (mod 7)
+assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+```
+*/
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::fraction::Fraction;
+use crate::helper::{mul_mod, square_and_multiply, ModInverse, GCD};
+
+#[test]
+fn modular_test() {
+    // Compare against plain 128 bit arithmetic for odd, even and large moduli
+    let mut state: u64 = 0x2545F4914F6CDD1D;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        return state;
+    };
+    for modulus in [1, 2, 3, 10, 998_244_353, 1 << 40, u64::MAX, u64::MAX - 1, (1 << 63) + 1] {
+        for _ in 0..200 {
+            let (a, b) = (next() % modulus, next() % modulus);
+            let (x, y) = (DynModInt::new(a as i128, modulus), DynModInt::new(b as i128, modulus));
+            assert_eq!((x * y).value() as u128, a as u128 * b as u128 % modulus as u128);
+            assert_eq!((x + y).value() as u128, (a as u128 + b as u128) % modulus as u128);
+            assert_eq!((x - y + y).value(), a);
+            if let Some(inverse) = x.inverse() {
+                assert_eq!((x * inverse).value(), 1 % modulus);
+            }
+        }
+    }
+    let x = ModInt::<{ u64::MAX }>::new(-1);
+    assert_eq!((x * x).value(), 1);
+    assert_eq!(x.pow(u64::MAX).value(), u64::MAX - 1);
+
+    assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+    assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(crt(&[]), Some((0, 1)));
+    assert_eq!(crt(&[(5, 0)]), None);
+    let large = u64::MAX as u128;
+    // 2^64 + 1 is 2 modulo 2^64 - 1, so its multiple with the inverse of 2 works
+    assert_eq!(crt(&[(1, large), (0, large + 2)]), Some(((large + 2) << 63, large * (large + 2))));
+    assert_eq!(crt(&[(1, 1 << 100), (2, (1 << 100) + 1)]), None);
+}
+
+// The constants for Montgomery multiplication with R = 2^64, or plain reduction for even moduli
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Montgomery {
+    modulus: u64,
+    // modulus^-1 mod 2^64
+    inverse: u64,
+    // R^2 mod modulus
+    r2: u64,
+}
+
+impl Montgomery {
+    const fn new(modulus: u64) -> Self {
+        if modulus == 0 {
+            panic!("The modulus can't be 0");
+        }
+        if modulus.is_multiple_of(2) {
+            return Montgomery { modulus, inverse: 0, r2: 0 };
+        }
+        // Newton's iteration doubles the correct bits each step, and every odd number is its own inverse modulo 8
+        let mut inverse = modulus;
+        let mut i = 0;
+        while i < 5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inverse)));
+            i += 1;
+        }
+        let r = (1u128 << 64) % modulus as u128;
+        return Montgomery { modulus, inverse, r2: (r * r % modulus as u128) as u64 };
+    }
+    const fn is_montgomery(&self) -> bool {
+        return self.modulus % 2 == 1;
+    }
+    // t * R^-1 mod modulus, for t < modulus * R
+    const fn reduce(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.inverse);
+        // The low halves of t and m * modulus are equal, so only the high halves need subtracting
+        let (high, subtract) = ((t >> 64) as u64, ((m as u128 * self.modulus as u128) >> 64) as u64);
+        return if high >= subtract { high - subtract } else { high.wrapping_sub(subtract).wrapping_add(self.modulus) };
+    }
+    // Convert a residue into Montgomery form
+    const fn encode(&self, value: u64) -> u64 {
+        if !self.is_montgomery() {
+            return value;
+        }
+        return self.reduce(value as u128 * self.r2 as u128);
+    }
+    // Convert out of Montgomery form back to a residue
+    const fn decode(&self, value: u64) -> u64 {
+        if !self.is_montgomery() {
+            return value;
+        }
+        return self.reduce(value as u128);
+    }
+    fn residue(&self, value: i128) -> u64 {
+        return self.encode(value.rem_euclid(self.modulus as i128) as u64);
+    }
+    fn add(&self, a: u64, b: u64) -> u64 {
+        let (sum, carry) = a.overflowing_add(b);
+        return if carry || sum >= self.modulus { sum.wrapping_sub(self.modulus) } else { sum };
+    }
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        return if a >= b { a - b } else { a.wrapping_sub(b).wrapping_add(self.modulus) };
+    }
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        if !self.is_montgomery() {
+            return (a as u128 * b as u128 % self.modulus as u128) as u64;
+        }
+        return self.reduce(a as u128 * b as u128);
+    }
+    fn pow(&self, base: u64, exponent: u64) -> u64 {
+        return square_and_multiply(base, self.encode(1 % self.modulus), exponent as u128, |a, b| Some(self.mul(a, b))).unwrap();
+    }
+    fn inverse(&self, value: u64) -> Option<u64> {
+        return Some(self.encode(self.decode(value).mod_inverse(self.modulus)?));
+    }
+}
+
+/// An integer modulo `M`, where the modulus is part of the type
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::modular::ModInt;
+/// 
+/// type Mod998244353 = ModInt<998_244_353>;
+/// 
+/// let x = Mod998244353::new(-1);
+/// 
+/// assert_eq!(x.value(), 998_244_352);
+/// assert_eq!((x * x).value(), 1);
+/// assert_eq!(Mod998244353::modulus(), 998_244_353);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    const MONTGOMERY: Montgomery = Montgomery::new(M);
+
+    /// Create the residue of any integer
+    pub fn new(value: i128) -> Self {
+        return ModInt { value: Self::MONTGOMERY.residue(value) };
+    }
+    /// The modulus `M`
+    pub fn modulus() -> u64 {
+        return M;
+    }
+    /// The residue, between 0 and the modulus
+    pub fn value(&self) -> u64 {
+        return Self::MONTGOMERY.decode(self.value);
+    }
+    /// Raise to a power by repeated squaring
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::modular::ModInt;
+    /// 
+    /// assert_eq!(ModInt::<1_000_000_007>::new(3).pow(1_000_000_006).value(), 1);
+    /// ```
+    pub fn pow(&self, exponent: u64) -> Self {
+        return ModInt { value: Self::MONTGOMERY.pow(self.value, exponent) };
+    }
+    /// The multiplicative inverse, found with the extended Euclidean algorithm,
+    /// or `None` if the value isn't coprime to the modulus
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::modular::ModInt;
+    /// 
+    /// assert_eq!(ModInt::<10>::new(3).inverse(), Some(ModInt::new(7)));
+    /// assert_eq!(ModInt::<10>::new(4).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        return Some(ModInt { value: Self::MONTGOMERY.inverse(self.value)? });
+    }
+    fn add_value(self, other: Self) -> Self {
+        return ModInt { value: Self::MONTGOMERY.add(self.value, other.value) };
+    }
+    fn sub_value(self, other: Self) -> Self {
+        return ModInt { value: Self::MONTGOMERY.sub(self.value, other.value) };
+    }
+    fn mul_value(self, other: Self) -> Self {
+        return ModInt { value: Self::MONTGOMERY.mul(self.value, other.value) };
+    }
+    fn neg_value(self) -> Self {
+        return ModInt { value: Self::MONTGOMERY.sub(0, self.value) };
+    }
+}
+
+impl<const M: u64> Default for ModInt<M> {
+    fn default() -> Self {
+        return ModInt::new(0);
+    }
+}
+
+/// An integer modulo a number chosen at runtime
+/// 
+/// # Panics
+/// Operations between values with different moduli panic
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::modular::DynModInt;
+/// 
+/// let x = DynModInt::new(10, 12);
+/// let y = DynModInt::new(5, 12);
+/// 
+/// assert_eq!((x + y).value(), 3);
+/// assert_eq!((x * y).value(), 2);
+/// assert_eq!(x.modulus(), 12);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DynModInt {
+    value: u64,
+    montgomery: Montgomery,
+}
+
+impl DynModInt {
+    /// Create the residue of any integer
+    /// 
+    /// # Panics
+    /// Panics if the modulus is 0
+    pub fn new(value: i128, modulus: u64) -> Self {
+        let montgomery = Montgomery::new(modulus);
+        return DynModInt { value: montgomery.residue(value), montgomery };
+    }
+    /// The modulus
+    pub fn modulus(&self) -> u64 {
+        return self.montgomery.modulus;
+    }
+    /// The residue, between 0 and the modulus
+    pub fn value(&self) -> u64 {
+        return self.montgomery.decode(self.value);
+    }
+    /// Raise to a power by repeated squaring
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::modular::DynModInt;
+    /// 
+    /// assert_eq!(DynModInt::new(4, 497).pow(13).value(), 445);
+    /// ```
+    pub fn pow(&self, exponent: u64) -> Self {
+        return DynModInt { value: self.montgomery.pow(self.value, exponent), montgomery: self.montgomery };
+    }
+    /// The multiplicative inverse, found with the extended Euclidean algorithm,
+    /// or `None` if the value isn't coprime to the modulus
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::modular::DynModInt;
+    /// 
+    /// assert_eq!(DynModInt::new(3, 11).inverse(), Some(DynModInt::new(4, 11)));
+    /// assert_eq!(DynModInt::new(6, 9).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        return Some(DynModInt { value: self.montgomery.inverse(self.value)?, montgomery: self.montgomery });
+    }
+    // The shared modulus of two values
+    fn common(&self, other: &Self) -> Montgomery {
+        if self.montgomery.modulus != other.montgomery.modulus {
+            panic!("Can't combine values modulo {} and {}", self.montgomery.modulus, other.montgomery.modulus);
+        }
+        return self.montgomery;
+    }
+    fn add_value(self, other: Self) -> Self {
+        let montgomery = self.common(&other);
+        return DynModInt { value: montgomery.add(self.value, other.value), montgomery };
+    }
+    fn sub_value(self, other: Self) -> Self {
+        let montgomery = self.common(&other);
+        return DynModInt { value: montgomery.sub(self.value, other.value), montgomery };
+    }
+    fn mul_value(self, other: Self) -> Self {
+        let montgomery = self.common(&other);
+        return DynModInt { value: montgomery.mul(self.value, other.value), montgomery };
+    }
+    fn neg_value(self) -> Self {
+        return DynModInt { value: self.montgomery.sub(0, self.value), montgomery: self.montgomery };
+    }
+}
+
+macro_rules! impl_mod_ops {
+    ([$($generics:tt)*] $t:ty) => {
+        impl<$($generics)*> Add for $t {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                return self.add_value(other);
+            }
+        }
+
+        impl<$($generics)*> Sub for $t {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                return self.sub_value(other);
+            }
+        }
+
+        impl<$($generics)*> Mul for $t {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                return self.mul_value(other);
+            }
+        }
+
+        impl<$($generics)*> Div for $t {
+            type Output = Self;
+
+            /// Multiply by the inverse
+            /// 
+            /// # Panics
+            /// Panics if `other` isn't coprime to the modulus
+            fn div(self, other: Self) -> Self {
+                match other.inverse() {
+                    Some(inverse) => return self.mul_value(inverse),
+                    None => panic!("{} has no inverse modulo {}", other.value(), self.modulus_value()),
+                }
+            }
+        }
+
+        impl<$($generics)*> Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                return self.neg_value();
+            }
+        }
+
+        impl<$($generics)*> AddAssign for $t {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<$($generics)*> SubAssign for $t {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<$($generics)*> MulAssign for $t {
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl<$($generics)*> DivAssign for $t {
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl<$($generics)*> Display for $t {
+            /// Display the residue
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                return write!(f, "{}", self.value());
+            }
+        }
+
+        impl<$($generics)*> Debug for $t {
+            /// Show the residue and the modulus
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                return write!(f, "{} (mod {})", self.value(), self.modulus_value());
+            }
+        }
+    };
+}
+
+impl<const M: u64> ModInt<M> {
+    fn modulus_value(&self) -> u64 {
+        return M;
+    }
+}
+
+impl DynModInt {
+    fn modulus_value(&self) -> u64 {
+        return self.modulus();
+    }
+}
+
+impl_mod_ops!([const M: u64] ModInt<M>);
+impl_mod_ops!([] DynModInt);
+
+/// Solve a system of congruences `x ≡ residue (mod modulus)` with the Chinese remainder theorem
+/// 
+/// The moduli don't have to be coprime. Returns `(x, lcm)` where `x` is the smallest non-negative solution
+/// and every solution is `x` plus a multiple of the lcm of the moduli,
+/// or `None` if the congruences contradict each other, a modulus is 0 or the lcm overflows
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::modular::crt;
+/// 
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// 
+/// // 6 and 4 share a factor of 2, and both residues are even
+/// assert_eq!(crt(&[(4, 6), (2, 4)]), Some((10, 12)));
+/// assert_eq!(crt(&[(4, 6), (1, 4)]), None);
+/// ```
+pub fn crt(congruences: &[(u128, u128)]) -> Option<(u128, u128)> {
+    let (mut x, mut lcm) = (0u128, 1u128);
+    for &(residue, modulus) in congruences {
+        if modulus == 0 {
+            return None;
+        }
+        // x + lcm * t ≡ residue (mod modulus), solved for t modulo modulus / gcd
+        let gcd = lcm.gcd(modulus);
+        let residue = residue % modulus;
+        let x_mod = x % modulus;
+        let difference = if residue >= x_mod { residue - x_mod } else { modulus - (x_mod - residue) };
+        if difference % gcd != 0 {
+            return None;
+        }
+        let reduced = modulus / gcd;
+        let t = mul_mod(difference / gcd, (lcm / gcd % reduced).mod_inverse(reduced)?, reduced);
+        // x + lcm * t is below the new lcm, so it can only overflow if the lcm does
+        let next = lcm.checked_mul(reduced)?;
+        x = x.checked_add(lcm.checked_mul(t)?)?;
+        lcm = next;
+    }
+    return Some((x, lcm));
+}
+
+impl Fraction {
+    /// Reduce the fraction modulo a number, as its numerator times the inverse of its denominator
+    /// 
+    /// Returns `None` if the denominator isn't coprime to the modulus
+    /// 
+    /// # Panics
+    /// Panics if the modulus is 0
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::fraction::Fraction;
+    /// use lemonmath::modular::DynModInt;
+    /// 
+    /// let p = 998_244_353;
+    /// let x = Fraction::new(-3, 4).to_mod(p).unwrap();
+    /// 
+    /// assert_eq!(x * DynModInt::new(4, p), DynModInt::new(-3, p));
+    /// assert_eq!(Fraction::new(1, 6).to_mod(9), None);
+    /// ```
+    pub fn to_mod(&self, modulus: u64) -> Option<DynModInt> {
+        let numerator = DynModInt::new(self.numerator, modulus);
+        return Some(numerator * DynModInt::new(self.denominator, modulus).inverse()?);
+    }
+}
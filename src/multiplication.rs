@@ -0,0 +1,303 @@
+/*!
+# Multiplication
+Cache-blocked matrix multiplication, optionally spread over several threads
+
+Products go through a tiled kernel that multiplies by a transposed copy of the right-hand matrix,
+so both operands are read along contiguous rows. `Matrix * Matrix` uses the same kernel on a single thread.
+
+With the `parallel` feature, [`Matrix::multiply`] splits the rows of the result between threads.
+
+Exact fraction products can use [`Matrix::strassen`] instead, which trades multiplications for additions.
+
+# Examples
+```rust
+use lemonmath::matrices::Matrix;
+use lemonmath::multiplication::MultiplyConfig;
+use lemonmath::fraction::Fraction;
+
+let a = Matrix::from_fn(100, 80, |row, col| (row + 2 * col) as f64);
+let b = Matrix::from_fn(80, 60, |row, col| (row as f64 - col as f64) / 10.0);
+
+let config = MultiplyConfig { block_size: 16, ..MultiplyConfig::default() };
+
+assert_eq!(a.multiply(&b, &config), Ok(a.clone() * b.clone()));
+
+// Fraction products with Strassen–Winograd recursion
+let x = Matrix::from_fn(20, 20, |row, col| Fraction::new((row * col % 5) as i128 - 2, (row + col) as i128 % 3 + 1));
+
+assert_eq!(x.strassen(&x, 4), Ok(x.clone() * x.clone()));
+```
+*/
+
+use std::ops::{AddAssign, Mul};
+
+#[cfg(not(feature = "no_fraction_math_traits"))]
+use crate::fraction::Fraction;
+use crate::matrices::{Matrix, MatrixError};
+
+#[test]
+fn multiplication_test() {
+    let a = Matrix::from_fn(37, 29, |row, col| (row * 3 + col * 7) as f64 / 7.0 - 2.0);
+    let b = Matrix::from_fn(29, 41, |row, col| (row as f64 * 1.5 - col as f64).sin());
+    let mut expected = Matrix::zeros(37, 41);
+    for row in 0..37 {
+        for x in 0..29 {
+            for col in 0..41 {
+                expected[(row, col)] += a[(row, x)] * b[(x, col)];
+            }
+        }
+    }
+    assert_eq!(a.clone() * b.clone(), expected);
+    for block_size in [1, 5, 8, 64] {
+        for threads in [1, 3, 16] {
+            let config = MultiplyConfig { block_size, threads };
+            assert_eq!(a.multiply(&b, &config), Ok(expected.clone()));
+        }
+    }
+    // Without the `parallel` feature, elements don't have to be thread-safe
+    #[cfg(not(feature = "parallel"))]
+    {
+        #[derive(Clone, Copy, Default, Debug, PartialEq)]
+        struct Local(i64, std::marker::PhantomData<*const ()>);
+        impl AddAssign for Local {
+            fn add_assign(&mut self, other: Self) {
+                self.0 += other.0;
+            }
+        }
+        impl Mul for Local {
+            type Output = Self;
+            fn mul(self, other: Self) -> Self {
+                return Local(self.0 * other.0, self.1);
+            }
+        }
+        let x = Matrix::from_fn(3, 3, |row, col| Local((row * 3 + col) as i64, std::marker::PhantomData));
+        assert_eq!(x.multiply(&x, &MultiplyConfig::default()), Ok(x.clone() * x.clone()));
+    }
+}
+
+/// Settings for [`Matrix::multiply`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MultiplyConfig {
+    /// The side length of the square tiles the product is computed in
+    pub block_size: usize,
+    /// The most threads to use with the `parallel` feature, where 0 uses the available parallelism.
+    /// Without the feature the product is always computed on the calling thread
+    pub threads: usize,
+}
+
+impl Default for MultiplyConfig {
+    /// Tiles of 64 by 64, using every available thread
+    fn default() -> Self {
+        return MultiplyConfig {
+            block_size: 64,
+            threads: 0
+        };
+    }
+}
+
+/// The thread-safety [`Matrix::multiply`] needs from its elements,
+/// which is `Send + Sync` with the `parallel` feature and nothing without it
+#[cfg(feature = "parallel")]
+pub trait Parallel: Send + Sync {}
+
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> Parallel for T {}
+
+/// The thread-safety [`Matrix::multiply`] needs from its elements,
+/// which is `Send + Sync` with the `parallel` feature and nothing without it
+#[cfg(not(feature = "parallel"))]
+pub trait Parallel {}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> Parallel for T {}
+
+impl<T: AddAssign + Default + Mul<Output = T> + Copy + Parallel> Matrix<T> {
+    /// Multiply two matrices with the cache-blocked kernel
+    /// 
+    /// The result is exactly the same as `self * other`, as every element is summed in the same order
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if `self` doesn't have as many columns as `other` has rows
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::multiplication::MultiplyConfig;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// let y = Matrix::new(3, 1, vec![1, 0, -1]);
+    /// 
+    /// assert_eq!(x.multiply(&y, &MultiplyConfig::default()), Ok(Matrix::new(2, 1, vec![-2, -2])));
+    /// assert!(y.multiply(&y, &MultiplyConfig::default()).is_err());
+    /// ```
+    pub fn multiply(&self, other: &Matrix<T>, config: &MultiplyConfig) -> Result<Matrix<T>, MatrixError> {
+        if self.cols() != other.rows() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let transposed = other.transpose();
+        let mut content = vec![T::default(); self.rows() * other.cols()];
+        let block_size = config.block_size.max(1);
+        #[cfg(feature = "parallel")]
+        {
+            let threads = match config.threads {
+                0 => std::thread::available_parallelism().map(|x| x.get()).unwrap_or(1),
+                threads => threads,
+            };
+            // Whole tiles of rows per thread, so no tile is split between threads
+            let blocks = self.rows().div_ceil(block_size);
+            let rows_per_thread = blocks.div_ceil(threads.max(1)).max(1) * block_size;
+            if threads > 1 && rows_per_thread < self.rows() && other.cols() > 0 {
+                std::thread::scope(|scope| {
+                    for (index, chunk) in content.chunks_mut(rows_per_thread * other.cols()).enumerate() {
+                        let (a, b) = (self.content(), transposed.content());
+                        scope.spawn(move || kernel(a, b, chunk, index * rows_per_thread, self.cols(), other.cols(), block_size));
+                    }
+                });
+                return Ok(Matrix::new(self.rows(), other.cols(), content));
+            }
+        }
+        kernel(self.content(), transposed.content(), &mut content, 0, self.cols(), other.cols(), block_size);
+        return Ok(Matrix::new(self.rows(), other.cols(), content));
+    }
+}
+
+#[cfg(not(feature = "no_fraction_math_traits"))]
+impl Matrix<Fraction> {
+    /// Multiply two fraction matrices with Strassen–Winograd recursion, which needs 7 half-size products instead of 8
+    /// 
+    /// Products with a dimension of at most `threshold` use classical multiplication.
+    /// Fraction arithmetic is exact and always reduced, so the result is identical to `self * other`,
+    /// although the larger intermediate sums can overflow sooner
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if `self` doesn't have as many columns as `other` has rows
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::fraction::Fraction;
+    /// 
+    /// let x = Matrix::from_fn(5, 3, |row, col| Fraction::new(1, (row + col + 1) as i128));
+    /// let y = Matrix::from_fn(3, 7, |row, col| Fraction::new(row as i128 - 1, (col + 2) as i128));
+    /// 
+    /// assert_eq!(x.strassen(&y, 1), Ok(x.clone() * y.clone()));
+    /// assert!(y.strassen(&x, 1).is_err());
+    /// ```
+    pub fn strassen(&self, other: &Matrix<Fraction>, threshold: usize) -> Result<Matrix<Fraction>, MatrixError> {
+        if self.cols() != other.rows() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        return Ok(winograd(self, other, threshold.max(1)));
+    }
+}
+
+// Strassen–Winograd recursion, padding odd dimensions with a zero row or column
+#[cfg(not(feature = "no_fraction_math_traits"))]
+fn winograd(a: &Matrix<Fraction>, b: &Matrix<Fraction>, threshold: usize) -> Matrix<Fraction> {
+    let (rows, inner, cols) = (a.rows(), a.cols(), b.cols());
+    if rows.min(inner).min(cols) <= threshold {
+        return multiply_blocked(a, b);
+    }
+    if rows % 2 == 1 || inner % 2 == 1 || cols % 2 == 1 {
+        let a = pad(a, rows + rows % 2, inner + inner % 2);
+        let b = pad(b, inner + inner % 2, cols + cols % 2);
+        return winograd(&a, &b, threshold).submatrix(0..rows, 0..cols);
+    }
+    let (rows, inner, cols) = (rows / 2, inner / 2, cols / 2);
+    let a11 = a.submatrix(0..rows, 0..inner);
+    let a12 = a.submatrix(0..rows, inner..2 * inner);
+    let a21 = a.submatrix(rows..2 * rows, 0..inner);
+    let a22 = a.submatrix(rows..2 * rows, inner..2 * inner);
+    let b11 = b.submatrix(0..inner, 0..cols);
+    let b12 = b.submatrix(0..inner, cols..2 * cols);
+    let b21 = b.submatrix(inner..2 * inner, 0..cols);
+    let b22 = b.submatrix(inner..2 * inner, cols..2 * cols);
+
+    let s1 = a21.clone() + a22.clone();
+    let s2 = s1.clone() - a11.clone();
+    let s3 = a11.clone() - a21;
+    let s4 = a12.clone() - s2.clone();
+    let t1 = b12.clone() - b11.clone();
+    let t2 = b22.clone() - t1.clone();
+    let t3 = b22.clone() - b12;
+    let t4 = t2.clone() - b21.clone();
+
+    let p1 = winograd(&a11, &b11, threshold);
+    let p2 = winograd(&a12, &b21, threshold);
+    let p3 = winograd(&s4, &b22, threshold);
+    let p4 = winograd(&a22, &t4, threshold);
+    let p5 = winograd(&s1, &t1, threshold);
+    let p6 = winograd(&s2, &t2, threshold);
+    let p7 = winograd(&s3, &t3, threshold);
+
+    let u2 = p1.clone() + p6;
+    let u3 = u2.clone() + p7;
+    let u4 = u2 + p5.clone();
+    let c11 = p1 + p2;
+    let c12 = u4 + p3;
+    let c21 = u3.clone() - p4;
+    let c22 = u3 + p5;
+    return Matrix::block(vec![vec![c11, c12], vec![c21, c22]]);
+}
+
+// Copy a matrix into the top left corner of a larger zero matrix
+#[cfg(not(feature = "no_fraction_math_traits"))]
+fn pad(matrix: &Matrix<Fraction>, rows: usize, cols: usize) -> Matrix<Fraction> {
+    if matrix.rows() == rows && matrix.cols() == cols {
+        return matrix.clone();
+    }
+    let mut result = Matrix::zeros(rows, cols);
+    result.set_block(0, 0, matrix).unwrap();
+    return result;
+}
+
+/// Multiply with the single-threaded blocked kernel, for `Matrix * Matrix`
+pub(crate) fn multiply_blocked<T: AddAssign + Default + Mul<Output = T> + Copy>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let transposed = b.transpose();
+    let mut content = vec![T::default(); a.rows() * b.cols()];
+    kernel(a.content(), transposed.content(), &mut content, 0, a.cols(), b.cols(), MultiplyConfig::default().block_size);
+    return Matrix::new(a.rows(), b.cols(), content);
+}
+
+// Accumulate the rows of `a * b` starting at `first_row` into `out`, where `b` is given transposed.
+// The inner dimension is the outer tile loop, so each element is summed in the same order as the naive product
+fn kernel<T: AddAssign + Mul<Output = T> + Copy>(a: &[T], transposed: &[T], out: &mut [T], first_row: usize, inner: usize, cols: usize, block_size: usize) {
+    if cols == 0 {
+        return;
+    }
+    let rows = out.len() / cols;
+    for row_block in (0..rows).step_by(block_size) {
+        let row_end = (row_block + block_size).min(rows);
+        for inner_block in (0..inner).step_by(block_size) {
+            let inner_end = (inner_block + block_size).min(inner);
+            for col_block in (0..cols).step_by(block_size) {
+                let col_end = (col_block + block_size).min(cols);
+                for row in row_block..row_end {
+                    let a_row = &a[(first_row + row) * inner + inner_block..(first_row + row) * inner + inner_end];
+                    let b_row = |col: usize| &transposed[col * inner + inner_block..col * inner + inner_end];
+                    let out_row = &mut out[row * cols..(row + 1) * cols];
+                    let mut col = col_block;
+                    // Four independent sums at a time, so they can be interleaved without reordering any of them
+                    while col + 4 <= col_end {
+                        let (b0, b1, b2, b3) = (b_row(col), b_row(col + 1), b_row(col + 2), b_row(col + 3));
+                        let mut sums = [out_row[col], out_row[col + 1], out_row[col + 2], out_row[col + 3]];
+                        for k in 0..a_row.len() {
+                            let x = a_row[k];
+                            sums[0] += x * b0[k];
+                            sums[1] += x * b1[k];
+                            sums[2] += x * b2[k];
+                            sums[3] += x * b3[k];
+                        }
+                        out_row[col..col + 4].copy_from_slice(&sums);
+                        col += 4;
+                    }
+                    for (col, sum) in (col..col_end).zip(&mut out_row[col..col_end]) {
+                        for (x, y) in a_row.iter().zip(b_row(col)) {
+                            *sum += *x * *y;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,241 @@
+/*!
+# Normal Forms
+Hermite and Smith normal forms of integer matrices
+
+# Examples
+```rust
+use lemonmath::matrices::Matrix;
+
+let a = Matrix::new(2, 2, vec![2i128, 4, 6, 8]);
+
+// Hermite normal form, H = UA
+let hermite = a.hermite_normal_form().unwrap();
+
+assert_eq!(hermite.h, Matrix::new(2, 2, vec![2, 0, 0, 4]));
+assert_eq!(hermite.u.clone() * a.clone(), hermite.h);
+
+// Smith normal form, D = UAV
+let smith = a.smith_normal_form().unwrap();
+
+assert_eq!(smith.d, Matrix::new(2, 2, vec![2, 0, 0, 4]));
+assert_eq!(smith.u.clone() * a.clone() * smith.v.clone(), smith.d);
+```
+*/
+
+use crate::helper::ExtendedGCD;
+use crate::matrices::{Matrix, MatrixError};
+
+#[test]
+fn normal_forms_test() {
+    let a = Matrix::new(3, 4, vec![4i128, -6, 0, 2, 10, 3, -5, 1, 2, 0, 7, -3]);
+    let hermite = a.hermite_normal_form().unwrap();
+    assert_eq!(hermite.u.clone() * a.clone(), hermite.h);
+    let smith = a.smith_normal_form().unwrap();
+    assert_eq!(smith.u.clone() * a.clone() * smith.v.clone(), smith.d);
+
+    // Negating a pivot of i128::MIN or dividing it by -1 overflows
+    let min = Matrix::new(1, 1, vec![i128::MIN]);
+    assert_eq!(min.hermite_normal_form(), Err(MatrixError::Overflow));
+    assert_eq!(min.smith_normal_form(), Err(MatrixError::Overflow));
+    let mixed = Matrix::new(2, 2, vec![i128::MIN, 1, -1, 0]);
+    assert_eq!(mixed.hermite_normal_form(), Err(MatrixError::Overflow));
+    assert_eq!(mixed.smith_normal_form(), Err(MatrixError::Overflow));
+}
+
+/// The Hermite normal form `H = UA` of an integer matrix
+#[derive(Clone, PartialEq, Debug)]
+pub struct HermiteNormalForm {
+    /// The row echelon form, with positive pivots and the entries above each pivot reduced modulo it
+    pub h: Matrix<i128>,
+    /// The unimodular transform applied to the rows
+    pub u: Matrix<i128>,
+}
+
+/// The Smith normal form `D = UAV` of an integer matrix
+#[derive(Clone, PartialEq, Debug)]
+pub struct SmithNormalForm {
+    /// The diagonal matrix of non-negative invariant factors, each dividing the next
+    pub d: Matrix<i128>,
+    /// The unimodular transform applied to the rows
+    pub u: Matrix<i128>,
+    /// The unimodular transform applied to the columns
+    pub v: Matrix<i128>,
+}
+
+impl Matrix<i128> {
+    /// The (row-style) Hermite normal form and the unimodular matrix that produces it
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::Overflow`] if an intermediate value doesn't fit in an `i128`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![3i128, 3, 1, 4, 1, 0, 0, 2, 19]);
+    /// let hermite = a.hermite_normal_form().unwrap();
+    /// 
+    /// assert_eq!(hermite.h, Matrix::new(3, 3, vec![1, 0, 18, 0, 1, 91, 0, 0, 163]));
+    /// assert_eq!(hermite.u * a, hermite.h);
+    /// ```
+    pub fn hermite_normal_form(&self) -> Result<HermiteNormalForm, MatrixError> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut h = self.clone();
+        let mut u = Matrix::identity(rows);
+        let mut row = 0;
+        for col in 0..cols {
+            if row == rows {
+                break;
+            }
+            for other in row + 1..rows {
+                if h[(other, col)] == 0 {
+                    continue;
+                }
+                let transform = bezout(h[(row, col)], h[(other, col)])?;
+                combine_rows(&mut h, row, other, transform)?;
+                combine_rows(&mut u, row, other, transform)?;
+            }
+            if h[(row, col)] == 0 {
+                continue;
+            }
+            if h[(row, col)] < 0 {
+                negate_row(&mut h, row)?;
+                negate_row(&mut u, row)?;
+            }
+            for above in 0..row {
+                let quotient = h[(above, col)].div_euclid(h[(row, col)]);
+                if quotient != 0 {
+                    combine_rows(&mut h, above, row, (1, -quotient, 0, 1))?;
+                    combine_rows(&mut u, above, row, (1, -quotient, 0, 1))?;
+                }
+            }
+            row += 1;
+        }
+        return Ok(HermiteNormalForm { h, u });
+    }
+    /// The Smith normal form and the unimodular matrices that produce it
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::Overflow`] if an intermediate value doesn't fit in an `i128`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![2i128, 4, 4, -6, 6, 12, 10, -4, -16]);
+    /// let smith = a.smith_normal_form().unwrap();
+    /// 
+    /// assert_eq!(smith.d, Matrix::new(3, 3, vec![2, 0, 0, 0, 6, 0, 0, 0, 12]));
+    /// assert_eq!(smith.u * a * smith.v, smith.d);
+    /// ```
+    pub fn smith_normal_form(&self) -> Result<SmithNormalForm, MatrixError> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut d = self.clone();
+        let mut u = Matrix::identity(rows);
+        let mut v = Matrix::identity(cols);
+        for t in 0..rows.min(cols) {
+            let mut pivot = None;
+            for row in t..rows {
+                for col in t..cols {
+                    if pivot.is_none() && d[(row, col)] != 0 {
+                        pivot = Some((row, col));
+                    }
+                }
+            }
+            let (pivot_row, pivot_col) = match pivot {
+                Some(pivot) => pivot,
+                None => break,
+            };
+            d.swap_rows(t, pivot_row);
+            u.swap_rows(t, pivot_row);
+            combine_cols(&mut d, t, pivot_col, (0, 1, 1, 0))?;
+            combine_cols(&mut v, t, pivot_col, (0, 1, 1, 0))?;
+            loop {
+                for row in t + 1..rows {
+                    if d[(row, t)] == 0 {
+                        continue;
+                    }
+                    let transform = bezout(d[(t, t)], d[(row, t)])?;
+                    combine_rows(&mut d, t, row, transform)?;
+                    combine_rows(&mut u, t, row, transform)?;
+                }
+                for col in t + 1..cols {
+                    if d[(t, col)] == 0 {
+                        continue;
+                    }
+                    let transform = bezout(d[(t, t)], d[(t, col)])?;
+                    combine_cols(&mut d, t, col, transform)?;
+                    combine_cols(&mut v, t, col, transform)?;
+                }
+                if (t + 1..rows).any(|row| d[(row, t)] != 0) {
+                    continue;
+                }
+                // Every remaining entry has to be a multiple of the pivot
+                let mut indivisible = None;
+                for row in t + 1..rows {
+                    for col in t + 1..cols {
+                        if indivisible.is_none() && d[(row, col)].checked_rem(d[(t, t)]).ok_or(MatrixError::Overflow)? != 0 {
+                            indivisible = Some(row);
+                        }
+                    }
+                }
+                match indivisible {
+                    Some(row) => {
+                        combine_rows(&mut d, t, row, (1, 1, 0, 1))?;
+                        combine_rows(&mut u, t, row, (1, 1, 0, 1))?;
+                    }
+                    None => break,
+                }
+            }
+            if d[(t, t)] < 0 {
+                negate_row(&mut d, t)?;
+                negate_row(&mut u, t)?;
+            }
+        }
+        return Ok(SmithNormalForm { d, u, v });
+    }
+}
+
+// The unimodular transform `(x, y, -b / gcd, a / gcd)` that takes `(a, b)` to `(gcd, 0)`
+fn bezout(a: i128, b: i128) -> Result<(i128, i128, i128, i128), MatrixError> {
+    let (gcd, x, y) = a.checked_extended_gcd(b).ok_or(MatrixError::Overflow)?;
+    let c = (b / gcd).checked_neg().ok_or(MatrixError::Overflow)?;
+    return Ok((x, y, c, a / gcd));
+}
+
+// Replace rows `first` and `second` with `a * first + b * second` and `c * first + d * second`
+fn combine_rows(matrix: &mut Matrix<i128>, first: usize, second: usize, (a, b, c, d): (i128, i128, i128, i128)) -> Result<(), MatrixError> {
+    for col in 0..matrix.cols() {
+        let (x, y) = (matrix[(first, col)], matrix[(second, col)]);
+        let new_first = combine(a, x, b, y)?;
+        let new_second = combine(c, x, d, y)?;
+        matrix[(first, col)] = new_first;
+        matrix[(second, col)] = new_second;
+    }
+    return Ok(());
+}
+
+// Replace columns `first` and `second` with `a * first + b * second` and `c * first + d * second`
+fn combine_cols(matrix: &mut Matrix<i128>, first: usize, second: usize, (a, b, c, d): (i128, i128, i128, i128)) -> Result<(), MatrixError> {
+    for row in 0..matrix.rows() {
+        let (x, y) = (matrix[(row, first)], matrix[(row, second)]);
+        let new_first = combine(a, x, b, y)?;
+        let new_second = combine(c, x, d, y)?;
+        matrix[(row, first)] = new_first;
+        matrix[(row, second)] = new_second;
+    }
+    return Ok(());
+}
+
+fn negate_row(matrix: &mut Matrix<i128>, row: usize) -> Result<(), MatrixError> {
+    for col in 0..matrix.cols() {
+        matrix[(row, col)] = matrix[(row, col)].checked_neg().ok_or(MatrixError::Overflow)?;
+    }
+    return Ok(());
+}
+
+fn combine(a: i128, x: i128, b: i128, y: i128) -> Result<i128, MatrixError> {
+    return a.checked_mul(x)
+        .and_then(|ax| b.checked_mul(y).and_then(|by| ax.checked_add(by)))
+        .ok_or(MatrixError::Overflow);
+}
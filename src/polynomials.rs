@@ -0,0 +1,329 @@
+/*!
+# Polynomials
+Polynomials, and the exact characteristic and minimal polynomials of fraction matrices
+
+# Examples
+```rust
+use lemonmath::polynomials::Polynomial;
+use lemonmath::matrices::Matrix;
+use lemonmath::fraction::Fraction;
+use lemonmath::helper::VecToFraction;
+
+// Create a polynomial from its coefficients, lowest degree first
+let p = Polynomial::new(vec![2, -3, 1]);
+
+assert_eq!(format!("{}", p), "x^2 - 3x + 2");
+assert_eq!(p.evaluate(2), 0);
+
+// Exact characteristic and minimal polynomials
+let a = Matrix::new(2, 2, vec![2, 1, 1, 2].to_fraction());
+
+assert_eq!(a.char_poly(), Ok(Polynomial::new(vec![3, -4, 1].to_fraction())));
+assert_eq!(a.minimal_poly(), a.char_poly());
+
+// Exact rational eigenvalues
+assert_eq!(a.char_poly().unwrap().rational_roots(), Some(vec![Fraction::new(1, 1), Fraction::new(3, 1)]));
+```
+*/
+
+use std::{fmt::Display, ops::{Add, Mul, Neg}};
+
+use crate::fraction::Fraction;
+use crate::helper::{GCD, LCM};
+use crate::matrices::{Matrix, MatrixError};
+use crate::primes::divisors;
+
+/// A polynomial in one variable
+#[derive(Clone, PartialEq, Debug)]
+pub struct Polynomial<T> {
+    coefficients: Vec<T>,
+}
+
+impl<T: Default + PartialEq> Polynomial<T> {
+    /// Create a polynomial from its coefficients, lowest degree first
+    /// 
+    /// Trailing zero coefficients are removed
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::polynomials::Polynomial;
+    /// 
+    /// // 3x^2 + 1
+    /// let p = Polynomial::new(vec![1, 0, 3, 0]);
+    /// 
+    /// assert_eq!(p.coefficients(), &[1, 0, 3]);
+    /// ```
+    pub fn new(mut coefficients: Vec<T>) -> Self {
+        while coefficients.last() == Some(&T::default()) {
+            coefficients.pop();
+        }
+        return Polynomial { coefficients };
+    }
+    /// The coefficients, lowest degree first
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::polynomials::Polynomial;
+    /// 
+    /// assert_eq!(Polynomial::new(vec![1, 2]).coefficients(), &[1, 2]);
+    /// ```
+    pub fn coefficients(&self) -> &[T] {
+        return &self.coefficients;
+    }
+    /// The degree of the polynomial, or `None` for the zero polynomial
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::polynomials::Polynomial;
+    /// 
+    /// assert_eq!(Polynomial::new(vec![1, 2, 3]).degree(), Some(2));
+    /// assert_eq!(Polynomial::new(vec![0]).degree(), None);
+    /// ```
+    pub fn degree(&self) -> Option<usize> {
+        return self.coefficients.len().checked_sub(1);
+    }
+}
+
+impl<T: Add<Output = T> + Mul<Output = T> + Default + Copy> Polynomial<T> {
+    /// Evaluate the polynomial with Horner's method
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::polynomials::Polynomial;
+    /// 
+    /// let p = Polynomial::new(vec![1.0, 0.0, 2.0]);
+    /// 
+    /// assert_eq!(p.evaluate(3.0), 19.0);
+    /// ```
+    pub fn evaluate(&self, x: T) -> T {
+        let mut result = T::default();
+        for coefficient in self.coefficients.iter().rev() {
+            result = result * x + *coefficient;
+        }
+        return result;
+    }
+}
+
+#[cfg(not(feature = "no_fraction_math_traits"))]
+impl Polynomial<Fraction> {
+    /// The distinct rational roots in ascending order, found with the rational root theorem
+    /// 
+    /// This tries every ratio of divisors of the (integer) constant and leading coefficients,
+    /// so it is slow when they have many divisors.
+    /// Returns `None` if clearing the denominators or testing a candidate overflows an `i128`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::polynomials::Polynomial;
+    /// use lemonmath::fraction::Fraction;
+    /// use lemonmath::helper::VecToFraction;
+    /// 
+    /// // (2x - 1)(x + 3)x^2 = 2x^4 + 5x^3 - 3x^2
+    /// let p = Polynomial::new(vec![0, 0, -3, 5, 2].to_fraction());
+    /// 
+    /// assert_eq!(p.rational_roots(), Some(vec![Fraction::new(-3, 1), Fraction::new(0, 1), Fraction::new(1, 2)]));
+    /// 
+    /// // x^2 + 1 has no rational roots
+    /// assert_eq!(Polynomial::new(vec![1, 0, 1].to_fraction()).rational_roots(), Some(vec![]));
+    /// 
+    /// // The common denominator doesn't fit in an i128
+    /// let p = Polynomial::new(vec![Fraction::new(1, 10i128.pow(21)), Fraction::new(1, 10i128.pow(21) - 1)]);
+    /// 
+    /// assert_eq!(p.rational_roots(), None);
+    /// ```
+    pub fn rational_roots(&self) -> Option<Vec<Fraction>> {
+        let mut roots = vec![];
+        let mut coefficients: &[Fraction] = &self.coefficients;
+        if coefficients.is_empty() {
+            return Some(roots);
+        }
+        while coefficients[0] == Fraction::default() {
+            if !roots.contains(&Fraction::default()) {
+                roots.push(Fraction::default());
+            }
+            coefficients = &coefficients[1..];
+        }
+        let mut denominator = 1i128;
+        for x in coefficients {
+            denominator = denominator.checked_lcm(x.denominator)?;
+        }
+        let scaled = coefficients.iter().map(|x| x.numerator.checked_mul(denominator / x.denominator)).collect::<Option<Vec<i128>>>()?;
+        let constant = scaled[0].unsigned_abs();
+        let leading = scaled[scaled.len() - 1].unsigned_abs();
+        let numerators: Vec<i128> = divisors(constant).into_iter().filter_map(|x| i128::try_from(x).ok()).collect();
+        let denominators: Vec<i128> = divisors(leading).into_iter().filter_map(|x| i128::try_from(x).ok()).collect();
+        for &p in &numerators {
+            for &q in denominators.iter().filter(|q| p.gcd(**q) == 1) {
+                for candidate in [p, -p] {
+                    if is_root(&scaled, candidate, q)? {
+                        roots.push(Fraction::new(candidate, q));
+                    }
+                }
+            }
+        }
+        roots.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        return Some(roots);
+    }
+}
+
+impl<T> Display for Polynomial<T>
+where
+    T: Display + PartialOrd + Default + Neg<Output = T> + From<u8> + Copy,
+{
+    /// Display the polynomial, highest degree first
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::polynomials::Polynomial;
+    /// use lemonmath::fraction::Fraction;
+    /// 
+    /// assert_eq!(format!("{}", Polynomial::new(vec![-1, 0, 0, 2])), "2x^3 - 1");
+    /// assert_eq!(format!("{}", Polynomial::new(vec![Fraction::new(1, 2), Fraction::new(-1, 3)])), "-(1/3)x + 1/2");
+    /// assert_eq!(format!("{}", Polynomial::<i32>::new(vec![])), "0");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.coefficients.is_empty() {
+            return f.write_str("0");
+        }
+        let mut result = String::new();
+        for (power, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if *coefficient == T::default() {
+                continue;
+            }
+            let negative = *coefficient < T::default();
+            let magnitude = if negative { -*coefficient } else { *coefficient };
+            if result.is_empty() {
+                if negative {
+                    result.push('-');
+                }
+            } else if negative {
+                result.push_str(" - ");
+            } else {
+                result.push_str(" + ");
+            }
+            let mut text = magnitude.to_string();
+            if power > 0 && text.contains('/') {
+                text = format!("({})", text);
+            }
+            if power == 0 || magnitude != T::from(1u8) {
+                result.push_str(&text);
+            }
+            match power {
+                0 => {}
+                1 => result.push('x'),
+                _ => result.push_str(&format!("x^{}", power)),
+            }
+        }
+        return f.write_str(&result);
+    }
+}
+
+#[cfg(not(feature = "no_fraction_math_traits"))]
+impl Matrix<Fraction> {
+    /// The exact characteristic polynomial `det(xI - A)`, using the Faddeev–LeVerrier algorithm
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::{Matrix, MatrixError};
+    /// use lemonmath::polynomials::Polynomial;
+    /// use lemonmath::helper::VecToFraction;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![2, 0, 0, 0, 3, 4, 0, 4, 9].to_fraction());
+    /// 
+    /// // (x - 2)(x - 1)(x - 11)
+    /// assert_eq!(a.char_poly(), Ok(Polynomial::new(vec![-22, 35, -14, 1].to_fraction())));
+    /// assert_eq!(Matrix::new(1, 2, vec![1, 2].to_fraction()).char_poly(), Err(MatrixError::NotSquare));
+    /// ```
+    pub fn char_poly(&self) -> Result<Polynomial<Fraction>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        let mut coefficients = vec![Fraction::default(); size + 1];
+        coefficients[size] = Fraction::from(1u8);
+        let mut m: Matrix<Fraction> = Matrix::zeros(size, size);
+        for k in 1..=size {
+            let mut next = self.clone() * m;
+            for x in 0..size {
+                next[(x, x)] += coefficients[size - k + 1];
+            }
+            let product = self.clone() * next.clone();
+            let mut trace = Fraction::default();
+            for x in 0..size {
+                trace += product[(x, x)];
+            }
+            coefficients[size - k] = -trace / Fraction::new(k as i128, 1);
+            m = next;
+        }
+        return Ok(Polynomial::new(coefficients));
+    }
+    /// The exact minimal polynomial, the monic polynomial of lowest degree with `p(A) = 0`
+    /// 
+    /// Found as the first linear dependency between `I, A, A^2, ...`
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::{Matrix, MatrixError};
+    /// use lemonmath::polynomials::Polynomial;
+    /// use lemonmath::helper::VecToFraction;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![2, 0, 0, 0, 2, 0, 0, 0, 3].to_fraction());
+    /// 
+    /// // (x - 2)(x - 3), while the characteristic polynomial is (x - 2)^2(x - 3)
+    /// assert_eq!(a.minimal_poly(), Ok(Polynomial::new(vec![6, -5, 1].to_fraction())));
+    /// assert_eq!(Matrix::new(2, 1, vec![1, 2].to_fraction()).minimal_poly(), Err(MatrixError::NotSquare));
+    /// ```
+    pub fn minimal_poly(&self) -> Result<Polynomial<Fraction>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = self.rows();
+        let zero = Fraction::default();
+        // Each row is a reduced power of A, with the combination of powers that produced it
+        let mut basis: Vec<(Vec<Fraction>, Vec<Fraction>, usize)> = vec![];
+        let mut power: Matrix<Fraction> = Matrix::identity(size);
+        for k in 0..=size {
+            let mut vector = power.content().to_vec();
+            let mut combination = vec![zero; k + 1];
+            combination[k] = Fraction::from(1u8);
+            for (row, row_combination, pivot) in &basis {
+                if vector[*pivot] == zero {
+                    continue;
+                }
+                let factor = vector[*pivot] / row[*pivot];
+                for (x, y) in vector.iter_mut().zip(row.iter()) {
+                    *x -= factor * *y;
+                }
+                for (x, y) in combination.iter_mut().zip(row_combination.iter()) {
+                    *x -= factor * *y;
+                }
+            }
+            match vector.iter().position(|x| *x != zero) {
+                Some(pivot) => basis.push((vector, combination, pivot)),
+                None => return Ok(Polynomial::new(combination)),
+            }
+            power = power * self.clone();
+        }
+        unreachable!("The Cayley–Hamilton theorem bounds the degree by the size of the matrix");
+    }
+}
+
+// Whether p/q is a root of the polynomial with integer coefficients c_i, by checking that the sum of c_i p^i q^(n - i) is zero
+#[cfg(not(feature = "no_fraction_math_traits"))]
+fn is_root(coefficients: &[i128], p: i128, q: i128) -> Option<bool> {
+    let mut value = 0i128;
+    let mut scale = 1i128;
+    for (x, coefficient) in coefficients.iter().rev().enumerate() {
+        if x > 0 {
+            scale = scale.checked_mul(q)?;
+        }
+        value = value.checked_mul(p)?.checked_add(coefficient.checked_mul(scale)?)?;
+    }
+    return Some(value == 0);
+}
@@ -0,0 +1,663 @@
+/*!
+# Primes
+Prime sieves, primality tests and factorization
+
+# Examples
+```rust
+use lemonmath::primes::{Primes, is_prime, is_prime_u128, factorize};
+
+// Iterate over the primes with a segmented sieve
+let primes: Vec<u64> = Primes::new().take(10).collect();
+
+assert_eq!(primes, vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+
+// Deterministic primality tests
+assert!(is_prime(1_000_000_007));
+assert!(!is_prime(561));
+assert!(is_prime_u128((1 << 127) - 1));
+
+// Factorize into a map of primes to exponents
+let factorization = factorize(360);
+
+assert_eq!(factorization.to_string(), "2^3 * 3^2 * 5");
+assert_eq!(factorization.euler_phi(), 96);
+assert_eq!(factorization.divisors().len(), 24);
+```
+*/
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use crate::helper::{mul_mod, pow_mod, GCD};
+
+#[test]
+fn primes_test() {
+    let sieved: Vec<u64> = Primes::up_to(100_000).collect();
+    let tested: Vec<u64> = (0..=100_000).filter(|n| is_prime(*n)).collect();
+    assert_eq!(sieved, tested);
+    assert!((0..=100_000u128).all(|n| is_prime_u128(n) == is_prime(n as u64)));
+
+    // The 100000th prime, about 40 segments in
+    assert_eq!(Primes::new().nth(99_999), Some(1_299_709));
+
+    // Strong pseudoprimes to several bases
+    for n in [3215031751u128, 3825123056546413051, 318665857834031151167461] {
+        assert!(!is_prime_u128(n));
+    }
+    let large_primes = [18446744073709551557u128, 170141183460469231731687303715884105727, 340282366920938463463374607431768211297];
+    assert!(large_primes.iter().all(|p| is_prime_u128(*p)));
+
+    for (p, q) in [(1_000_000_007u128, 998_244_353), (18446744073709551557, 4294967291), (4611686018427387847, 4611686018427387847)] {
+        let factorization = factorize(p * q);
+        assert_eq!(factorization.value(), p * q);
+        assert!(factorization.iter().all(|(p, _)| is_prime_u128(p)));
+    }
+    assert_eq!(factorize(u128::MAX).factors().keys().copied().collect::<Vec<_>>(), vec![3, 5, 17, 257, 641, 65537, 274177, 6700417, 67280421310721]);
+}
+
+// The length of each segment of the sieve
+const SEGMENT_SIZE: u64 = 1 << 15;
+
+// The bases that make Miller–Rabin deterministic below 3.3 * 10^24, which covers every u64
+const WITNESSES: [u128; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// Factors below this are found by trial division before Pollard's rho
+const TRIAL_DIVISION_LIMIT: u64 = 1000;
+
+/// An iterator over the primes in increasing order, using a segmented sieve of Eratosthenes
+/// 
+/// Only one segment is kept in memory at a time, along with the primes up to its square root
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::Primes;
+/// 
+/// assert_eq!(Primes::up_to(20).collect::<Vec<_>>(), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// assert_eq!(Primes::new().nth(999), Some(7919));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Primes {
+    limit: u64,
+    // The start of the next segment, or `None` once the limit is reached
+    low: Option<u64>,
+    segment: Vec<u64>,
+    index: usize,
+    base: Vec<u64>,
+    base_limit: u64,
+}
+
+impl Primes {
+    /// Iterate over every prime that fits in a `u64`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::Primes;
+    /// 
+    /// let first: Vec<u64> = Primes::new().take(5).collect();
+    /// 
+    /// assert_eq!(first, vec![2, 3, 5, 7, 11]);
+    /// assert_eq!(Primes::new().nth(999), Some(7919));
+    /// ```
+    pub fn new() -> Self {
+        return Primes::up_to(u64::MAX);
+    }
+    /// Iterate over the primes up to and including `limit`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::Primes;
+    /// 
+    /// assert_eq!(Primes::up_to(13).collect::<Vec<u64>>(), vec![2, 3, 5, 7, 11, 13]);
+    /// assert_eq!(Primes::up_to(1_000_000).count(), 78_498);
+    /// assert_eq!(Primes::up_to(1).next(), None);
+    /// ```
+    pub fn up_to(limit: u64) -> Self {
+        return Primes {
+            limit,
+            low: Some(0),
+            segment: vec![],
+            index: 0,
+            base: vec![],
+            base_limit: 0,
+        };
+    }
+    // Sieve the next segment with the primes up to its square root
+    fn sieve_segment(&mut self, low: u64) {
+        let high = low.saturating_add(SEGMENT_SIZE - 1).min(self.limit);
+        let root = high.isqrt();
+        if root > self.base_limit {
+            self.base_limit = root.max(self.base_limit * 2);
+            self.base = simple_sieve(self.base_limit);
+        }
+        let mut composite = vec![false; (high - low + 1) as usize];
+        for &p in &self.base {
+            if p > root {
+                break;
+            }
+            // Start at the first multiple in the segment, but not below p^2
+            let first = match low.div_ceil(p).checked_mul(p) {
+                Some(first) => first.max(p * p),
+                None => continue,
+            };
+            if first > high {
+                continue;
+            }
+            for multiple in (first..=high).step_by(p as usize) {
+                composite[(multiple - low) as usize] = true;
+            }
+        }
+        self.segment = (low..=high).filter(|n| *n >= 2 && !composite[(n - low) as usize]).collect();
+        self.index = 0;
+        self.low = match high < self.limit {
+            true => Some(high + 1),
+            false => None,
+        };
+    }
+}
+
+impl Default for Primes {
+    fn default() -> Self {
+        return Primes::new();
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.index == self.segment.len() {
+            self.sieve_segment(self.low?);
+        }
+        self.index += 1;
+        return Some(self.segment[self.index - 1]);
+    }
+}
+
+/// All the primes up to and including `limit`
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::sieve;
+/// 
+/// assert_eq!(sieve(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+/// assert_eq!(sieve(1), vec![]);
+/// ```
+pub fn sieve(limit: u64) -> Vec<u64> {
+    return Primes::up_to(limit).collect();
+}
+
+// An unsegmented sieve for the base primes
+fn simple_sieve(limit: u64) -> Vec<u64> {
+    let mut composite = vec![false; limit as usize + 1];
+    let mut primes = vec![];
+    for n in 2..=limit {
+        if !composite[n as usize] {
+            primes.push(n);
+            for multiple in (n.saturating_mul(n)..=limit).step_by(n as usize) {
+                composite[multiple as usize] = true;
+            }
+        }
+    }
+    return primes;
+}
+
+/// Test if a number is prime, using Miller–Rabin with enough bases to be deterministic for every `u64`
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::is_prime;
+/// 
+/// assert!(is_prime(2));
+/// assert!(is_prime(18446744073709551557));
+/// assert!(!is_prime(1));
+/// assert!(!is_prime(3215031751));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    let n = n as u128;
+    if n < 2 {
+        return false;
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    return WITNESSES.iter().all(|a| strong_probable_prime(n, *a));
+}
+
+/// Test if a number is prime, using the strong Baillie–PSW test above `u64::MAX`
+/// 
+/// Baillie–PSW combines a base 2 Miller–Rabin test with a strong Lucas test.
+/// No composite number is known to pass it, though that hasn't been proven for every `u128`
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::is_prime_u128;
+/// 
+/// // The largest prime below 2^128
+/// assert!(is_prime_u128(u128::MAX - 158));
+/// assert!(!is_prime_u128(u128::MAX - 156));
+/// // A strong pseudoprime to the first 12 prime bases
+/// assert!(!is_prime_u128(318665857834031151167461));
+/// ```
+pub fn is_prime_u128(n: u128) -> bool {
+    if n <= u64::MAX as u128 {
+        return is_prime(n as u64);
+    }
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return false;
+        }
+    }
+    return strong_probable_prime(n, 2) && strong_lucas_probable_prime(n);
+}
+
+// Miller–Rabin for a single base, where n is odd and bigger than the base
+fn strong_probable_prime(n: u128, base: u128) -> bool {
+    let shift = (n - 1).trailing_zeros();
+    let d = (n - 1) >> shift;
+    let mut x = pow_mod(base, d, n);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    for _ in 1..shift {
+        x = mul_mod(x, x, n);
+        if x == n - 1 {
+            return true;
+        }
+    }
+    return false;
+}
+
+// The strong Lucas test with Selfridge's parameters, where n is odd and has no small factors
+fn strong_lucas_probable_prime(n: u128) -> bool {
+    // No suitable D exists for squares
+    let root = n.isqrt();
+    if root * root == n {
+        return false;
+    }
+    // The first D in 5, -7, 9, -11, ... with (D/n) = -1
+    let mut d: i128 = 5;
+    loop {
+        let residue = signed_mod(d, n);
+        match jacobi(residue, n) {
+            -1 => break,
+            0 if d.unsigned_abs() != n => return false,
+            _ => d = if d > 0 { -d - 2 } else { -d + 2 },
+        }
+    }
+    let (p, q, d) = (1, signed_mod((1 - d) / 4, n), signed_mod(d, n));
+    // n + 1 = k * 2^shift with k odd
+    let shift = (n + 1).trailing_zeros();
+    let k = (n + 1) >> shift;
+    // Walk up the bits of k, doubling the index and adding one where a bit is set
+    let (mut u, mut v, mut q_k) = (1, p, q);
+    for bit in (0..127 - k.leading_zeros()).rev() {
+        u = mul_mod(u, v, n);
+        v = sub_mod(mul_mod(v, v, n), add_mod(q_k, q_k, n), n);
+        q_k = mul_mod(q_k, q_k, n);
+        if (k >> bit) & 1 == 1 {
+            (u, v) = (half_mod(add_mod(mul_mod(p, u, n), v, n), n), half_mod(add_mod(mul_mod(d, u, n), mul_mod(p, v, n), n), n));
+            q_k = mul_mod(q_k, q, n);
+        }
+    }
+    if u == 0 {
+        return true;
+    }
+    for _ in 0..shift {
+        if v == 0 {
+            return true;
+        }
+        v = sub_mod(mul_mod(v, v, n), add_mod(q_k, q_k, n), n);
+        q_k = mul_mod(q_k, q_k, n);
+    }
+    return false;
+}
+
+// The Jacobi symbol (a/n) for odd n
+fn jacobi(mut a: u128, mut n: u128) -> i32 {
+    let mut result = 1;
+    a %= n;
+    while a != 0 {
+        while a.is_multiple_of(2) {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        (a, n) = (n, a);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    return if n == 1 { result } else { 0 };
+}
+
+fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    let (sum, carry) = a.overflowing_add(b);
+    return if carry || sum >= modulus { sum.wrapping_sub(modulus) } else { sum };
+}
+
+fn sub_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    return if a >= b { a - b } else { modulus - (b - a) };
+}
+
+// Divide by 2 modulo an odd modulus
+fn half_mod(a: u128, modulus: u128) -> u128 {
+    return if a.is_multiple_of(2) { a / 2 } else { a / 2 + modulus / 2 + 1 };
+}
+
+fn signed_mod(a: i128, modulus: u128) -> u128 {
+    let remainder = a.unsigned_abs() % modulus;
+    return if a < 0 && remainder != 0 { modulus - remainder } else { remainder };
+}
+
+/// The prime factorization of a positive number, as a map from each prime to its exponent
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::factorize;
+/// 
+/// let factorization = factorize(1_000_000_016_000_000_063);
+/// 
+/// assert_eq!(factorization.exponent(1_000_000_007), 1);
+/// assert_eq!(factorization.exponent(1_000_000_009), 1);
+/// assert_eq!(factorization.to_string(), "1000000007 * 1000000009");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct Factorization {
+    factors: BTreeMap<u128, u32>,
+}
+
+impl Factorization {
+    /// The map from each prime factor to its exponent
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// let factorization = factorize(360);
+    /// 
+    /// assert_eq!(factorization.factors().len(), 3);
+    /// assert_eq!(factorization.factors().get(&2), Some(&3));
+    /// assert!(factorize(1).factors().is_empty());
+    /// ```
+    pub fn factors(&self) -> &BTreeMap<u128, u32> {
+        return &self.factors;
+    }
+    /// Iterate over the primes and their exponents in increasing order
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// let pairs: Vec<(u128, u32)> = factorize(360).iter().collect();
+    /// 
+    /// assert_eq!(pairs, vec![(2, 3), (3, 2), (5, 1)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (u128, u32)> + '_ {
+        return self.factors.iter().map(|(p, e)| (*p, *e));
+    }
+    /// The exponent of a prime, which is 0 if it isn't a factor
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// let factorization = factorize(1 << 100);
+    /// 
+    /// assert_eq!(factorization.exponent(2), 100);
+    /// assert_eq!(factorization.exponent(3), 0);
+    /// ```
+    pub fn exponent(&self, prime: u128) -> u32 {
+        return self.factors.get(&prime).copied().unwrap_or(0);
+    }
+    /// The number that was factorized
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// assert_eq!(factorize(720).value(), 720);
+    /// ```
+    pub fn value(&self) -> u128 {
+        return self.iter().map(|(p, e)| p.pow(e)).product();
+    }
+    /// Every divisor in increasing order
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// assert_eq!(factorize(12).divisors(), vec![1, 2, 3, 4, 6, 12]);
+    /// assert_eq!(factorize(1).divisors(), vec![1]);
+    /// ```
+    pub fn divisors(&self) -> Vec<u128> {
+        let mut result = vec![1];
+        for (p, e) in self.iter() {
+            let previous = result.len();
+            let mut power = 1;
+            for _ in 0..e {
+                power *= p;
+                for i in 0..previous {
+                    result.push(result[i] * power);
+                }
+            }
+        }
+        result.sort_unstable();
+        return result;
+    }
+    /// Euler's totient, the count of numbers up to the value that are coprime to it
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// assert_eq!(factorize(36).euler_phi(), 12);
+    /// assert_eq!(factorize(1).euler_phi(), 1);
+    /// ```
+    pub fn euler_phi(&self) -> u128 {
+        return self.iter().map(|(p, e)| p.pow(e - 1) * (p - 1)).product();
+    }
+    /// The Möbius function, which is 0 unless the value is square-free,
+    /// and otherwise 1 or -1 for an even or odd number of prime factors
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// assert_eq!(factorize(30).mobius(), -1);
+    /// assert_eq!(factorize(18).mobius(), 0);
+    /// assert_eq!(factorize(1).mobius(), 1);
+    /// ```
+    pub fn mobius(&self) -> i8 {
+        if self.factors.values().any(|e| *e > 1) {
+            return 0;
+        }
+        return if self.factors.len().is_multiple_of(2) { 1 } else { -1 };
+    }
+    /// The sum of the `k`th powers of the divisors, or `None` if it overflows.
+    /// `sigma(0)` counts the divisors and `sigma(1)` adds them up
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// assert_eq!(factorize(12).sigma(0), Some(6));
+    /// assert_eq!(factorize(12).sigma(1), Some(28));
+    /// assert_eq!(factorize(12).sigma(2), Some(210));
+    /// assert_eq!(factorize(1 << 100).sigma(2), None);
+    /// ```
+    pub fn sigma(&self, k: u32) -> Option<u128> {
+        let mut result: u128 = 1;
+        for (p, e) in self.iter() {
+            let base = p.checked_pow(k)?;
+            let (mut sum, mut power) = (1u128, 1u128);
+            for _ in 0..e {
+                power = power.checked_mul(base)?;
+                sum = sum.checked_add(power)?;
+            }
+            result = result.checked_mul(sum)?;
+        }
+        return Some(result);
+    }
+}
+
+impl Display for Factorization {
+    /// Display the factorization as a product of prime powers
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::primes::factorize;
+    /// 
+    /// assert_eq!(format!("{}", factorize(1400)), "2^3 * 5^2 * 7");
+    /// assert_eq!(format!("{}", factorize(1)), "1");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.factors.is_empty() {
+            return write!(f, "1");
+        }
+        let terms: Vec<String> = self.iter().map(|(p, e)| if e == 1 { p.to_string() } else { format!("{}^{}", p, e) }).collect();
+        return write!(f, "{}", terms.join(" * "));
+    }
+}
+
+/// Factorize a number with trial division and Pollard's rho
+/// 
+/// # Panics
+/// Panics if the number is 0
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::factorize;
+/// 
+/// let factorization = factorize(2u128.pow(64) + 1);
+/// 
+/// assert_eq!(factorization.to_string(), "274177 * 67280421310721");
+/// ```
+pub fn factorize(n: u128) -> Factorization {
+    if n == 0 {
+        panic!("0 has no prime factorization");
+    }
+    let mut factors = BTreeMap::new();
+    let mut n = n;
+    for p in Primes::up_to(TRIAL_DIVISION_LIMIT) {
+        let p = p as u128;
+        while n.is_multiple_of(p) {
+            *factors.entry(p).or_insert(0) += 1;
+            n /= p;
+        }
+    }
+    if n > 1 {
+        split(n, &mut factors);
+    }
+    return Factorization { factors };
+}
+
+// Split a number with no small factors into primes
+fn split(n: u128, factors: &mut BTreeMap<u128, u32>) {
+    if is_prime_u128(n) {
+        *factors.entry(n).or_insert(0) += 1;
+        return;
+    }
+    // Pollard's rho is slowest on squares of large primes, so take those apart directly
+    let root = n.isqrt();
+    if root * root == n {
+        split(root, factors);
+        split(root, factors);
+        return;
+    }
+    for c in 1.. {
+        if let Some(divisor) = pollard_brent(n, c) {
+            split(divisor, factors);
+            split(n / divisor, factors);
+            return;
+        }
+    }
+}
+
+// Pollard's rho with Brent's cycle detection, multiplying differences together to take fewer gcds
+fn pollard_brent(n: u128, c: u128) -> Option<u128> {
+    let f = |x: u128| add_mod(mul_mod(x, x, n), c, n);
+    let batch = 128;
+    let (mut x, mut y, mut saved) = (2, 2, 2);
+    let (mut length, mut product, mut gcd) = (1, 1, 1);
+    while gcd == 1 {
+        x = y;
+        for _ in 0..length {
+            y = f(y);
+        }
+        let mut steps = 0;
+        while steps < length && gcd == 1 {
+            saved = y;
+            for _ in 0..batch.min(length - steps) {
+                y = f(y);
+                product = mul_mod(product, x.abs_diff(y), n);
+            }
+            gcd = product.gcd(n);
+            steps += batch;
+        }
+        length *= 2;
+    }
+    if gcd == n {
+        // The batch overshot, so step through it again one gcd at a time
+        loop {
+            saved = f(saved);
+            gcd = x.abs_diff(saved).gcd(n);
+            if gcd > 1 {
+                break;
+            }
+        }
+    }
+    return if gcd == n { None } else { Some(gcd) };
+}
+
+/// Every divisor of a positive number in increasing order, see [`Factorization::divisors`]
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::divisors;
+/// 
+/// assert_eq!(divisors(28), vec![1, 2, 4, 7, 14, 28]);
+/// ```
+pub fn divisors(n: u128) -> Vec<u128> {
+    return factorize(n).divisors();
+}
+
+/// Euler's totient of a positive number, see [`Factorization::euler_phi`]
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::euler_phi;
+/// 
+/// assert_eq!(euler_phi(1_000_000_007), 1_000_000_006);
+/// ```
+pub fn euler_phi(n: u128) -> u128 {
+    return factorize(n).euler_phi();
+}
+
+/// The Möbius function of a positive number, see [`Factorization::mobius`]
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::mobius;
+/// 
+/// assert_eq!(mobius(6), 1);
+/// ```
+pub fn mobius(n: u128) -> i8 {
+    return factorize(n).mobius();
+}
+
+/// The sum of the `k`th powers of the divisors of a positive number, see [`Factorization::sigma`]
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::primes::sigma;
+/// 
+/// // 28 is a perfect number
+/// assert_eq!(sigma(28, 1), Some(56));
+/// ```
+pub fn sigma(n: u128, k: u32) -> Option<u128> {
+    return factorize(n).sigma(k);
+}
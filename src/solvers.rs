@@ -0,0 +1,620 @@
+/*!
+# Solvers
+Iterative solvers for large sparse linear systems
+
+# Examples
+```rust
+use lemonmath::sparse::CooMatrix;
+use lemonmath::solvers::{conjugate_gradient, ILU0, SolverConfig};
+use lemonmath::vectors::Vector;
+
+// The 1D Laplacian, a symmetric positive-definite finite-difference matrix
+let n = 1000;
+let mut a = CooMatrix::new(n, n);
+for i in 0..n {
+    a.push(i, i, 2.0);
+    if i > 0 {
+        a.push(i, i - 1, -1.0);
+        a.push(i - 1, i, -1.0);
+    }
+}
+let a = a.to_csr();
+let b = Vector::new(vec![1.0; n], true);
+
+let solution = conjugate_gradient(&a, &b, None, SolverConfig::default()).unwrap();
+let residual = a.mul_dense(&solution.x) - b.clone();
+
+assert!(residual.iter().all(|x| x.abs() < 1e-6));
+
+// The convergence history holds the relative residual after each iteration
+assert_eq!(solution.history.len(), solution.iterations);
+assert!(*solution.history.last().unwrap() <= SolverConfig::default().tolerance);
+
+// ILU(0) is exact for a tridiagonal matrix, so one iteration is enough
+let ilu = ILU0::new(&a).unwrap();
+let solution = conjugate_gradient(&a, &b, Some(&ilu), SolverConfig::default()).unwrap();
+
+assert_eq!(solution.iterations, 1);
+```
+*/
+
+use crate::matrices::{Matrix, MatrixError};
+use crate::sparse::{CscMatrix, CsrMatrix};
+use crate::vectors::Vector;
+
+#[test]
+fn solvers_test() {
+    // The 2D Laplacian on a 20x20 grid, where ILU(0) drops fill-in
+    let (n, size) = (20, 400);
+    let mut a = crate::sparse::CooMatrix::new(size, size);
+    for i in 0..n {
+        for j in 0..n {
+            let row = i * n + j;
+            a.push(row, row, 4.0);
+            if i > 0 {
+                a.push(row, row - n, -1.0);
+            }
+            if i + 1 < n {
+                a.push(row, row + n, -1.0);
+            }
+            if j > 0 {
+                a.push(row, row - 1, -1.0);
+            }
+            if j + 1 < n {
+                a.push(row, row + 1, -1.0);
+            }
+        }
+    }
+    let a = a.to_csr();
+    let b: Vector<f64> = (0..size).map(|x| (x % 7) as f64).collect();
+    let (jacobi, ilu) = (Jacobi::new(&a).unwrap(), ILU0::new(&a).unwrap());
+    let config = SolverConfig { restart: 20, ..SolverConfig::default() };
+    let mut iterations = vec![];
+    for preconditioner in [None, Some(&jacobi as &dyn Preconditioner), Some(&ilu as &dyn Preconditioner)] {
+        for solver in [conjugate_gradient, bicgstab, gmres] {
+            let solution = solver(&a, &b, preconditioner, config).unwrap();
+            let residual = b.clone() - a.mul_dense(&solution.x);
+            assert!(norm(&residual) <= 1e-9 * norm(&b));
+            iterations.push(solution.iterations);
+        }
+    }
+    // ILU(0) needs fewer iterations than no preconditioner for every solver
+    for x in 0..3 {
+        assert!(iterations[6 + x] < iterations[x]);
+    }
+    // Wide operators are rejected before they are applied
+    let wide = Matrix::new(2, 3, vec![1.0, 0.0, 2.0, 0.0, 1.0, 3.0]);
+    let b = Vector::new(vec![1.0, 1.0], true);
+    for solver in [conjugate_gradient, bicgstab, gmres] {
+        assert_eq!(solver(&wide, &b, None, config), Err(MatrixError::NotSquare));
+        assert_eq!(solver(&crate::sparse::CsrMatrix::from_dense(&wide), &b, None, config), Err(MatrixError::NotSquare));
+    }
+    // So are row vectors, even with the right length
+    let square = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 3.0]);
+    let row = Vector::new(vec![1.0, 1.0], false);
+    for solver in [conjugate_gradient, bicgstab, gmres] {
+        assert_eq!(solver(&square, &row, None, config), Err(MatrixError::DimensionMismatch));
+        assert_eq!(solver(&crate::sparse::CsrMatrix::from_dense(&square), &row, None, config), Err(MatrixError::DimensionMismatch));
+    }
+}
+
+/// Something that can be multiplied by a column vector, so it can be solved without storing a matrix
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::solvers::{gmres, LinearOperator, SolverConfig};
+/// use lemonmath::vectors::Vector;
+/// 
+/// // The matrix-free operator x -> (x[i] * (i + 1))
+/// struct Scaling(usize);
+/// 
+/// impl LinearOperator for Scaling {
+///     fn size(&self) -> usize {
+///         return self.0;
+///     }
+///     fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+///         return x.iter().enumerate().map(|(i, value)| value * (i + 1) as f64).collect();
+///     }
+/// }
+/// 
+/// let b = Vector::new(vec![1.0, 4.0, 9.0], true);
+/// let solution = gmres(&Scaling(3), &b, None, SolverConfig::default()).unwrap();
+/// 
+/// assert!((solution.x - Vector::new(vec![1.0, 2.0, 3.0], true)).iter().all(|x| x.abs() < 1e-12));
+/// ```
+pub trait LinearOperator {
+    /// The number of rows and columns of the square operator
+    fn size(&self) -> usize;
+    /// Multiply the operator by a column vector of length [`LinearOperator::size`]
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64>;
+    /// Whether the operator is square, which the solvers check before calling [`LinearOperator::apply`]
+    fn is_square(&self) -> bool {
+        return true;
+    }
+}
+
+impl LinearOperator for CsrMatrix<f64> {
+    fn size(&self) -> usize {
+        return self.rows();
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        return self.mul_dense(x);
+    }
+    fn is_square(&self) -> bool {
+        return self.rows() == self.cols();
+    }
+}
+
+impl LinearOperator for CscMatrix<f64> {
+    fn size(&self) -> usize {
+        return self.rows();
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        return self.mul_dense(x);
+    }
+    fn is_square(&self) -> bool {
+        return self.rows() == self.cols();
+    }
+}
+
+impl LinearOperator for Matrix<f64> {
+    fn size(&self) -> usize {
+        return self.rows();
+    }
+    fn apply(&self, x: &Vector<f64>) -> Vector<f64> {
+        return (0..self.rows()).map(|row| (0..self.cols()).map(|col| self[(row, col)] * x[col]).sum()).collect();
+    }
+    fn is_square(&self) -> bool {
+        return self.rows() == self.cols();
+    }
+}
+
+/// An approximate inverse applied to residuals to speed up convergence
+pub trait Preconditioner {
+    /// Apply the approximate inverse to a column vector
+    fn apply(&self, r: &Vector<f64>) -> Vector<f64>;
+}
+
+/// The Jacobi preconditioner, dividing by the diagonal of the matrix
+#[derive(Clone, PartialEq, Debug)]
+pub struct Jacobi {
+    inverse_diagonal: Vec<f64>,
+}
+
+impl Jacobi {
+    /// Create the Jacobi preconditioner of a square sparse matrix
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// and [`MatrixError::Singular`] if an element of the diagonal is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// use lemonmath::solvers::{Jacobi, Preconditioner};
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let a = CooMatrix::from_triplets(2, 2, vec![(0, 0, 2.0), (1, 1, 4.0), (0, 1, 1.0)]).to_csr();
+    /// let jacobi = Jacobi::new(&a).unwrap();
+    /// 
+    /// assert_eq!(jacobi.apply(&Vector::new(vec![1.0, 1.0], true)), Vector::new(vec![0.5, 0.25], true));
+    /// ```
+    pub fn new(matrix: &CsrMatrix<f64>) -> Result<Self, MatrixError> {
+        if matrix.rows() != matrix.cols() {
+            return Err(MatrixError::NotSquare);
+        }
+        let mut inverse_diagonal = Vec::with_capacity(matrix.rows());
+        for x in 0..matrix.rows() {
+            let value = matrix.get(x, x);
+            if value == 0.0 {
+                return Err(MatrixError::Singular);
+            }
+            inverse_diagonal.push(1.0 / value);
+        }
+        return Ok(Jacobi { inverse_diagonal });
+    }
+}
+
+impl Preconditioner for Jacobi {
+    fn apply(&self, r: &Vector<f64>) -> Vector<f64> {
+        return r.iter().zip(self.inverse_diagonal.iter()).map(|(x, y)| x * y).collect();
+    }
+}
+
+/// The incomplete LU preconditioner with no fill-in, ILU(0)
+/// 
+/// The factors keep the sparsity pattern of the matrix, so they are only exact when no fill-in would occur
+#[derive(Clone, PartialEq, Debug)]
+pub struct ILU0 {
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    // The strict lower part holds L without its unit diagonal, the rest holds U
+    values: Vec<f64>,
+    diagonal: Vec<usize>,
+}
+
+impl ILU0 {
+    /// Compute the incomplete factorization of a square sparse matrix
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NotSquare`] if the matrix isn't square
+    /// and [`MatrixError::Singular`] if a pivot is zero or missing from the sparsity pattern
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CsrMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::solvers::{ILU0, Preconditioner};
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// // Without fill-in the factorization is exact, so it solves the system
+    /// let a = CsrMatrix::from_dense(&Matrix::new(2, 2, vec![4.0, 1.0, 2.0, 3.0]));
+    /// let ilu = ILU0::new(&a).unwrap();
+    /// 
+    /// assert_eq!(ilu.apply(&Vector::new(vec![5.0, 5.0], true)), Vector::new(vec![1.0, 1.0], true));
+    /// ```
+    pub fn new(matrix: &CsrMatrix<f64>) -> Result<Self, MatrixError> {
+        if matrix.rows() != matrix.cols() {
+            return Err(MatrixError::NotSquare);
+        }
+        let size = matrix.rows();
+        let row_offsets = matrix.row_offsets().to_vec();
+        let col_indices = matrix.col_indices().to_vec();
+        let mut values = matrix.values().to_vec();
+        let mut diagonal = Vec::with_capacity(size);
+        for row in 0..size {
+            match col_indices[row_offsets[row]..row_offsets[row + 1]].binary_search(&row) {
+                Ok(position) => diagonal.push(row_offsets[row] + position),
+                Err(_) => return Err(MatrixError::Singular),
+            }
+        }
+        for row in 0..size {
+            for position in row_offsets[row]..diagonal[row] {
+                let k = col_indices[position];
+                if values[diagonal[k]] == 0.0 {
+                    return Err(MatrixError::Singular);
+                }
+                values[position] /= values[diagonal[k]];
+                let factor = values[position];
+                // Only update elements of row `row` that are already in the pattern
+                let mut target = position + 1;
+                for source in diagonal[k] + 1..row_offsets[k + 1] {
+                    let col = col_indices[source];
+                    while target < row_offsets[row + 1] && col_indices[target] < col {
+                        target += 1;
+                    }
+                    if target < row_offsets[row + 1] && col_indices[target] == col {
+                        values[target] -= factor * values[source];
+                    }
+                }
+            }
+            if values[diagonal[row]] == 0.0 {
+                return Err(MatrixError::Singular);
+            }
+        }
+        return Ok(ILU0 { row_offsets, col_indices, values, diagonal });
+    }
+}
+
+impl Preconditioner for ILU0 {
+    fn apply(&self, r: &Vector<f64>) -> Vector<f64> {
+        let size = self.diagonal.len();
+        let mut result = r.to_vec();
+        // Forward substitution with the unit lower factor
+        for row in 0..size {
+            for position in self.row_offsets[row]..self.diagonal[row] {
+                result[row] -= self.values[position] * result[self.col_indices[position]];
+            }
+        }
+        // Backward substitution with the upper factor
+        for row in (0..size).rev() {
+            for position in self.diagonal[row] + 1..self.row_offsets[row + 1] {
+                result[row] -= self.values[position] * result[self.col_indices[position]];
+            }
+            result[row] /= self.values[self.diagonal[row]];
+        }
+        return Vector::new(result, true);
+    }
+}
+
+/// Convergence settings for the iterative solvers
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolverConfig {
+    /// The relative residual `|b - Ax| / |b|` at which the solution is accepted
+    pub tolerance: f64,
+    /// The most iterations (matrix-vector products for GMRES) before giving up
+    pub max_iterations: usize,
+    /// The number of GMRES iterations between restarts
+    pub restart: usize,
+}
+
+impl Default for SolverConfig {
+    /// A tolerance of 1e-10, up to 1000 iterations and restarts every 30 GMRES iterations
+    fn default() -> Self {
+        return SolverConfig {
+            tolerance: 1e-10,
+            max_iterations: 1000,
+            restart: 30
+        };
+    }
+}
+
+/// The solution found by an iterative solver, with its convergence history
+#[derive(Clone, PartialEq, Debug)]
+pub struct Solution {
+    /// The solution as a column vector
+    pub x: Vector<f64>,
+    /// The number of iterations it took
+    pub iterations: usize,
+    /// The relative residual after each iteration
+    pub history: Vec<f64>,
+}
+
+/// Solve a symmetric positive-definite system `Ax = b` with the (preconditioned) conjugate gradient method
+/// 
+/// The preconditioner should be symmetric positive-definite too
+/// 
+/// # Errors
+/// Returns [`MatrixError::NotSquare`] if the operator isn't square,
+/// [`MatrixError::DimensionMismatch`] if `b` isn't a column vector with [`LinearOperator::size`] elements
+/// and [`MatrixError::NoConvergence`] if the tolerance isn't reached within the iteration limit
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::matrices::Matrix;
+/// use lemonmath::solvers::{conjugate_gradient, SolverConfig};
+/// use lemonmath::vectors::Vector;
+/// 
+/// let a = Matrix::new(2, 2, vec![4.0, 1.0, 1.0, 3.0]);
+/// let b = Vector::new(vec![1.0, 2.0], true);
+/// let solution = conjugate_gradient(&a, &b, None, SolverConfig::default()).unwrap();
+/// 
+/// assert!((solution.x[0] - 1.0 / 11.0).abs() < 1e-12);
+/// assert!((solution.x[1] - 7.0 / 11.0).abs() < 1e-12);
+/// ```
+pub fn conjugate_gradient(a: &dyn LinearOperator, b: &Vector<f64>, preconditioner: Option<&dyn Preconditioner>, config: SolverConfig) -> Result<Solution, MatrixError> {
+    let size = check_dimensions(a, b)?;
+    let b_norm = norm(b);
+    let mut x = Vector::new(vec![0.0; size], true);
+    let mut history = vec![];
+    if b_norm == 0.0 {
+        return Ok(Solution { x, iterations: 0, history });
+    }
+    let mut r = b.clone();
+    let mut z = precondition(preconditioner, &r);
+    let mut p = z.clone();
+    let mut rz = r.dot(&z);
+    for iteration in 1..=config.max_iterations {
+        let ap = a.apply(&p);
+        let alpha = rz / p.dot(&ap);
+        axpy(&mut x, alpha, &p);
+        axpy(&mut r, -alpha, &ap);
+        history.push(norm(&r) / b_norm);
+        if history[history.len() - 1] <= config.tolerance {
+            return Ok(Solution { x, iterations: iteration, history });
+        }
+        z = precondition(preconditioner, &r);
+        let next = r.dot(&z);
+        let beta = next / rz;
+        rz = next;
+        for (p, z) in p.iter_mut().zip(z.iter()) {
+            *p = z + beta * *p;
+        }
+    }
+    return Err(MatrixError::NoConvergence);
+}
+
+/// Solve a general system `Ax = b` with the (right preconditioned) BiCGSTAB method
+/// 
+/// # Errors
+/// Returns [`MatrixError::NotSquare`] if the operator isn't square,
+/// [`MatrixError::DimensionMismatch`] if `b` isn't a column vector with [`LinearOperator::size`] elements
+/// and [`MatrixError::NoConvergence`] if the method breaks down or the tolerance isn't reached within the iteration limit
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::sparse::CooMatrix;
+/// use lemonmath::solvers::{bicgstab, Jacobi, SolverConfig};
+/// use lemonmath::vectors::Vector;
+/// 
+/// // A non-symmetric convection-diffusion matrix
+/// let n = 100;
+/// let mut a = CooMatrix::new(n, n);
+/// for i in 0..n {
+///     a.push(i, i, 3.0);
+///     if i > 0 {
+///         a.push(i, i - 1, -2.0);
+///         a.push(i - 1, i, -0.5);
+///     }
+/// }
+/// let a = a.to_csr();
+/// let b = Vector::new(vec![1.0; n], true);
+/// 
+/// let jacobi = Jacobi::new(&a).unwrap();
+/// let solution = bicgstab(&a, &b, Some(&jacobi), SolverConfig::default()).unwrap();
+/// let residual = a.mul_dense(&solution.x) - b;
+/// 
+/// assert!(residual.iter().all(|x| x.abs() < 1e-8));
+/// ```
+pub fn bicgstab(a: &dyn LinearOperator, b: &Vector<f64>, preconditioner: Option<&dyn Preconditioner>, config: SolverConfig) -> Result<Solution, MatrixError> {
+    let size = check_dimensions(a, b)?;
+    let b_norm = norm(b);
+    let mut x = Vector::new(vec![0.0; size], true);
+    let mut history = vec![];
+    if b_norm == 0.0 {
+        return Ok(Solution { x, iterations: 0, history });
+    }
+    let mut r = b.clone();
+    let shadow = r.clone();
+    let (mut rho, mut alpha, mut omega) = (1.0, 1.0, 1.0);
+    let mut v = Vector::new(vec![0.0; size], true);
+    let mut p = Vector::new(vec![0.0; size], true);
+    for iteration in 1..=config.max_iterations {
+        let next = shadow.dot(&r);
+        if next == 0.0 || omega == 0.0 {
+            return Err(MatrixError::NoConvergence);
+        }
+        let beta = (next / rho) * (alpha / omega);
+        rho = next;
+        for ((p, r), v) in p.iter_mut().zip(r.iter()).zip(v.iter()) {
+            *p = r + beta * (*p - omega * v);
+        }
+        let y = precondition(preconditioner, &p);
+        v = a.apply(&y);
+        alpha = rho / shadow.dot(&v);
+        let mut s = r.clone();
+        axpy(&mut s, -alpha, &v);
+        axpy(&mut x, alpha, &y);
+        if norm(&s) / b_norm <= config.tolerance {
+            history.push(norm(&s) / b_norm);
+            return Ok(Solution { x, iterations: iteration, history });
+        }
+        let z = precondition(preconditioner, &s);
+        let t = a.apply(&z);
+        omega = t.dot(&s) / t.dot(&t);
+        axpy(&mut x, omega, &z);
+        r = s;
+        axpy(&mut r, -omega, &t);
+        history.push(norm(&r) / b_norm);
+        if history[history.len() - 1] <= config.tolerance {
+            return Ok(Solution { x, iterations: iteration, history });
+        }
+    }
+    return Err(MatrixError::NoConvergence);
+}
+
+/// Solve a general system `Ax = b` with the restarted (right preconditioned) GMRES method
+/// 
+/// Every iteration is one matrix-vector product, and the Krylov basis is rebuilt every [`SolverConfig::restart`] iterations
+/// 
+/// # Errors
+/// Returns [`MatrixError::NotSquare`] if the operator isn't square,
+/// [`MatrixError::DimensionMismatch`] if `b` isn't a column vector with [`LinearOperator::size`] elements,
+/// [`MatrixError::Singular`] if the least-squares problem in the Krylov basis is singular, which only happens for a singular operator,
+/// and [`MatrixError::NoConvergence`] if the tolerance isn't reached within the iteration limit
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::matrices::Matrix;
+/// use lemonmath::solvers::{gmres, SolverConfig};
+/// use lemonmath::vectors::Vector;
+/// 
+/// let a = Matrix::new(3, 3, vec![2.0, 1.0, 0.0, 0.0, 3.0, 1.0, 1.0, 0.0, 4.0]);
+/// let b = Vector::new(vec![3.0, 4.0, 5.0], true);
+/// let solution = gmres(&a, &b, None, SolverConfig::default()).unwrap();
+/// 
+/// // GMRES finishes within n iterations in exact arithmetic
+/// assert!(solution.iterations <= 3);
+/// assert!((solution.x - Vector::new(vec![1.0, 1.0, 1.0], true)).iter().all(|x| x.abs() < 1e-12));
+/// ```
+pub fn gmres(a: &dyn LinearOperator, b: &Vector<f64>, preconditioner: Option<&dyn Preconditioner>, config: SolverConfig) -> Result<Solution, MatrixError> {
+    let size = check_dimensions(a, b)?;
+    let b_norm = norm(b);
+    let mut x = Vector::new(vec![0.0; size], true);
+    let mut history = vec![];
+    if b_norm == 0.0 {
+        return Ok(Solution { x, iterations: 0, history });
+    }
+    let restart = config.restart.max(1);
+    while history.len() < config.max_iterations {
+        let mut r = b.clone();
+        axpy(&mut r, -1.0, &a.apply(&x));
+        let beta = norm(&r);
+        if beta / b_norm <= config.tolerance {
+            return Ok(Solution { x, iterations: history.len(), history });
+        }
+        let mut basis = vec![r.scale(1.0 / beta)];
+        let mut h: Vec<Vec<f64>> = vec![];
+        let (mut cosines, mut sines): (Vec<f64>, Vec<f64>) = (vec![], vec![]);
+        let mut g = vec![beta];
+        let mut converged = false;
+        while basis.len() <= restart && history.len() < config.max_iterations {
+            let j = basis.len() - 1;
+            let mut w = a.apply(&precondition(preconditioner, &basis[j]));
+            // Arnoldi with modified Gram–Schmidt
+            let mut column = vec![0.0; j + 2];
+            for (i, v) in basis.iter().enumerate() {
+                column[i] = w.dot(v);
+                axpy(&mut w, -column[i], v);
+            }
+            column[j + 1] = norm(&w);
+            // Apply the earlier rotations, then zero the new subdiagonal element
+            for i in 0..j {
+                let (x, y) = (column[i], column[i + 1]);
+                column[i] = cosines[i] * x + sines[i] * y;
+                column[i + 1] = -sines[i] * x + cosines[i] * y;
+            }
+            let radius = column[j].hypot(column[j + 1]);
+            let (cosine, sine) = if radius == 0.0 { (1.0, 0.0) } else { (column[j] / radius, column[j + 1] / radius) };
+            let breakdown = column[j + 1] == 0.0;
+            if !breakdown {
+                basis.push(w.scale(1.0 / column[j + 1]));
+            }
+            column[j] = radius;
+            column[j + 1] = 0.0;
+            g.push(-sine * g[j]);
+            g[j] *= cosine;
+            cosines.push(cosine);
+            sines.push(sine);
+            h.push(column);
+            history.push(g[j + 1].abs() / b_norm);
+            if history[history.len() - 1] <= config.tolerance {
+                converged = true;
+                break;
+            }
+            if breakdown {
+                break;
+            }
+        }
+        // Solve the small upper triangular system and update x
+        let k = h.len();
+        let mut y = vec![0.0; k];
+        for i in (0..k).rev() {
+            let mut sum = g[i];
+            for (l, y) in y.iter().enumerate().skip(i + 1) {
+                sum -= h[l][i] * y;
+            }
+            if h[i][i] == 0.0 {
+                return Err(MatrixError::Singular);
+            }
+            y[i] = sum / h[i][i];
+        }
+        let mut update = Vector::new(vec![0.0; size], true);
+        for (v, y) in basis.iter().zip(y.iter()) {
+            axpy(&mut update, *y, v);
+        }
+        axpy(&mut x, 1.0, &precondition(preconditioner, &update));
+        if converged {
+            return Ok(Solution { x, iterations: history.len(), history });
+        }
+    }
+    return Err(MatrixError::NoConvergence);
+}
+
+fn check_dimensions(a: &dyn LinearOperator, b: &Vector<f64>) -> Result<usize, MatrixError> {
+    if !a.is_square() {
+        return Err(MatrixError::NotSquare);
+    }
+    if !b.is_column() || b.len() != a.size() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    return Ok(a.size());
+}
+
+fn precondition(preconditioner: Option<&dyn Preconditioner>, r: &Vector<f64>) -> Vector<f64> {
+    return match preconditioner {
+        Some(preconditioner) => preconditioner.apply(r),
+        None => r.clone(),
+    };
+}
+
+fn norm(x: &Vector<f64>) -> f64 {
+    return x.dot(x).sqrt();
+}
+
+// y += alpha * x
+fn axpy(y: &mut Vector<f64>, alpha: f64, x: &Vector<f64>) {
+    for (y, x) in y.iter_mut().zip(x.iter()) {
+        *y += alpha * x;
+    }
+}
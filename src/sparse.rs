@@ -0,0 +1,1793 @@
+/*!
+# Sparse
+Sparse Math Vectors and Matrices that only store their non-zero elements
+
+# Examples
+```rust
+use lemonmath::sparse::SparseVector;
+use lemonmath::vectors::Vector;
+
+// Create a SparseVector from its length and (index, value) pairs
+let x = SparseVector::from_pairs(100_000, vec![(3, 2.0), (99_999, 1.0)], false);
+let y = SparseVector::from_pairs(100_000, vec![(3, 4.0), (7, 5.0)], true);
+
+assert_eq!(x.nnz(), 2);
+
+// Dot Product (row times column)
+assert_eq!(x.clone() * y, 8.0);
+
+// Dot Product with a dense vector
+let dense = Vector::new(vec![1.0, 2.0, 3.0, 4.0], true);
+let sparse = SparseVector::from_pairs(4, vec![(1, 10.0)], false);
+
+assert_eq!(sparse.inner_dense(&dense), Some(20.0));
+
+// Convert to and from dense vectors
+assert_eq!(sparse.to_dense(), Vector::new(vec![0.0, 10.0, 0.0, 0.0], false));
+assert_eq!(SparseVector::from(sparse.to_dense()), sparse);
+
+// Assemble a sparse matrix from triplets, then compress it for fast products
+use lemonmath::sparse::CooMatrix;
+
+let n = 100_000;
+let mut laplacian = CooMatrix::new(n, n);
+for i in 0..n {
+    laplacian.push(i, i, 2.0);
+    if i > 0 {
+        laplacian.push(i, i - 1, -1.0);
+    }
+    if i + 1 < n {
+        laplacian.push(i, i + 1, -1.0);
+    }
+}
+let laplacian = laplacian.to_csr();
+let ones = Vector::new(vec![1.0; n], true);
+
+assert_eq!(laplacian.nnz(), 3 * n - 2);
+assert_eq!(laplacian.mul_dense(&ones).iter().sum::<f64>(), 2.0);
+```
+*/
+
+use std::{fmt::Display, ops::{Add, AddAssign, Mul, Sub}};
+
+use crate::matrices::Matrix;
+use crate::vectors::Vector;
+
+#[test]
+fn sparse_matrix_test() {
+    let a = Matrix::new(3, 4, vec![1, 0, 0, 2, 0, 0, 3, 0, -4, 5, 0, 0]);
+    let b = Matrix::new(4, 2, vec![0, 1, 2, 0, 0, 0, 3, -1]);
+    let x = Vector::new(vec![1, 2, 3, 4], true);
+    let (csr, csc) = (CsrMatrix::from_dense(&a), CscMatrix::from_dense(&a));
+    assert_eq!(csr.mul_dense(&x), a.clone() * x.clone());
+    assert_eq!(csc.mul_dense(&x), a.clone() * x);
+    assert_eq!(csr.mul_sparse(&CsrMatrix::from_dense(&b)).to_dense(), a.clone() * b.clone());
+    assert_eq!(csc.mul_sparse(&CscMatrix::from_dense(&b)).to_dense(), a.clone() * b);
+    assert_eq!(csr.transpose().to_dense(), a.transpose());
+    assert_eq!((csr.clone() - csc.to_csr()).nnz(), 0);
+}
+
+/// Sparse Math Vector
+/// 
+/// Only the non-zero elements are stored, as pairs of indices and values sorted by index
+#[derive(Clone, PartialEq, Debug)]
+pub struct SparseVector<T> {
+    len: usize,
+    indices: Vec<usize>,
+    values: Vec<T>,
+    column_or_row: bool
+}
+
+impl<T> SparseVector<T> {
+    /// Create an empty (all zero) SparseVector with the given length
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// // The bool is to determine if the vector is a column or row
+    /// let x: SparseVector<f64> = SparseVector::new(10, true);
+    /// 
+    /// assert_eq!(x.len(), 10);
+    /// assert_eq!(x.nnz(), 0);
+    /// ```
+    pub fn new(len: usize, column_or_row: bool) -> Self {
+        return SparseVector {
+            len,
+            indices: vec![],
+            values: vec![],
+            column_or_row
+        };
+    }
+    /// The length of the vector, counting the zero elements
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(5, vec![(1, 2)], true);
+    /// 
+    /// assert_eq!(x.len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+    /// Check if the vector has a length of zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x: SparseVector<i32> = SparseVector::new(0, true);
+    /// 
+    /// assert!(x.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+    /// The number of stored (non-zero) elements
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(5, vec![(1, 2), (3, 0)], true);
+    /// 
+    /// assert_eq!(x.nnz(), 1);
+    /// ```
+    pub fn nnz(&self) -> usize {
+        return self.values.len();
+    }
+    /// The sorted indices of the stored elements
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(5, vec![(3, 1), (1, 2)], true);
+    /// 
+    /// assert_eq!(x.indices(), &[1, 3]);
+    /// ```
+    pub fn indices(&self) -> &[usize] {
+        return &self.indices;
+    }
+    /// The stored elements, in the same order as [`SparseVector::indices`]
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(5, vec![(3, 1), (1, 2)], true);
+    /// 
+    /// assert_eq!(x.values(), &[2, 1]);
+    /// ```
+    pub fn values(&self) -> &[T] {
+        return &self.values;
+    }
+    /// Iterate over the stored elements as (index, value) pairs
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(5, vec![(3, 1), (1, 2)], true);
+    /// 
+    /// assert_eq!(x.iter().collect::<Vec<_>>(), vec![(1, &2), (3, &1)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        return self.indices.iter().copied().zip(self.values.iter());
+    }
+    /// Switch between column and row vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let mut x = SparseVector::from_pairs(5, vec![(1, 2)], true);
+    /// 
+    /// x.transpose();
+    /// 
+    /// assert!(!x.is_column());
+    /// ```
+    pub fn transpose(&mut self) {
+        self.column_or_row = !self.column_or_row;
+    }
+    /// Check if the vector is a column vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(5, vec![(1, 2)], true);
+    /// 
+    /// assert!(x.is_column());
+    /// ```
+    pub fn is_column(&self) -> bool {
+        return self.column_or_row;
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> SparseVector<T> {
+    /// Create a SparseVector from its length and (index, value) pairs in any order
+    /// 
+    /// Values given for the same index are added together and zeros are not stored
+    /// 
+    /// # Panics
+    /// Panics if an index is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(5, vec![(3, 1), (1, 2), (3, 4)], true);
+    /// 
+    /// assert_eq!(x.get(3), 5);
+    /// assert_eq!(x.get(0), 0);
+    /// ```
+    pub fn from_pairs(len: usize, mut pairs: Vec<(usize, T)>, column_or_row: bool) -> Self {
+        pairs.sort_by_key(|pair| pair.0);
+        let mut result = SparseVector::new(len, column_or_row);
+        for (index, value) in pairs {
+            if index >= len {
+                panic!("Index {} is out of bounds for a sparse vector of length {}", index, len);
+            }
+            if result.indices.last() == Some(&index) {
+                *result.values.last_mut().unwrap() += value;
+            } else {
+                result.indices.push(index);
+                result.values.push(value);
+            }
+        }
+        result.remove_zeros();
+        return result;
+    }
+    /// Create a SparseVector from the non-zero elements of a dense vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = SparseVector::from_dense(&Vector::new(vec![0, 3, 0, 4], false));
+    /// 
+    /// assert_eq!(x, SparseVector::from_pairs(4, vec![(1, 3), (3, 4)], false));
+    /// ```
+    pub fn from_dense(vector: &Vector<T>) -> Self {
+        let mut result = SparseVector::new(vector.len(), vector.is_column());
+        for (index, value) in vector.iter().enumerate() {
+            if *value != T::default() {
+                result.indices.push(index);
+                result.values.push(*value);
+            }
+        }
+        return result;
+    }
+    /// Convert the vector into a dense vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = SparseVector::from_pairs(4, vec![(1, 3), (3, 4)], true);
+    /// 
+    /// assert_eq!(x.to_dense(), Vector::new(vec![0, 3, 0, 4], true));
+    /// ```
+    pub fn to_dense(&self) -> Vector<T> {
+        let mut content = vec![T::default(); self.len];
+        for (index, value) in self.iter() {
+            content[index] = *value;
+        }
+        return Vector::new(content, self.column_or_row);
+    }
+    /// Get an element of the vector, which is zero if it isn't stored
+    /// 
+    /// # Panics
+    /// Panics if the index is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(4, vec![(1, 3)], true);
+    /// 
+    /// assert_eq!(x.get(1), 3);
+    /// assert_eq!(x.get(2), 0);
+    /// ```
+    pub fn get(&self, index: usize) -> T {
+        if index >= self.len {
+            panic!("Index {} is out of bounds for a sparse vector of length {}", index, self.len);
+        }
+        return match self.indices.binary_search(&index) {
+            Ok(position) => self.values[position],
+            Err(_) => T::default(),
+        };
+    }
+    /// Set an element of the vector, removing it from storage if the value is zero
+    /// 
+    /// # Panics
+    /// Panics if the index is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let mut x = SparseVector::from_pairs(4, vec![(1, 3)], true);
+    /// 
+    /// x.insert(2, 5);
+    /// x.insert(1, 0);
+    /// 
+    /// assert_eq!(x, SparseVector::from_pairs(4, vec![(2, 5)], true));
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        if index >= self.len {
+            panic!("Index {} is out of bounds for a sparse vector of length {}", index, self.len);
+        }
+        match self.indices.binary_search(&index) {
+            Ok(position) => {
+                if value == T::default() {
+                    self.indices.remove(position);
+                    self.values.remove(position);
+                } else {
+                    self.values[position] = value;
+                }
+            }
+            Err(position) => {
+                if value != T::default() {
+                    self.indices.insert(position, index);
+                    self.values.insert(position, value);
+                }
+            }
+        }
+    }
+
+    fn remove_zeros(&mut self) {
+        let mut kept = 0;
+        for position in 0..self.values.len() {
+            if self.values[position] != T::default() {
+                self.indices[kept] = self.indices[position];
+                self.values[kept] = self.values[position];
+                kept += 1;
+            }
+        }
+        self.indices.truncate(kept);
+        self.values.truncate(kept);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> SparseVector<T> {
+    /// Multiply every element of the vector by a scalar
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(4, vec![(1, 3)], true);
+    /// 
+    /// assert_eq!(x.scale(2), SparseVector::from_pairs(4, vec![(1, 6)], true));
+    /// assert_eq!(x.scale(0).nnz(), 0);
+    /// ```
+    pub fn scale(&self, factor: T) -> Self {
+        let mut result = self.clone();
+        for value in result.values.iter_mut() {
+            *value = *value * factor;
+        }
+        result.remove_zeros();
+        return result;
+    }
+    /// Dot product of two sparse vectors of the same length, ignoring their orientation
+    /// 
+    /// This only visits the stored elements of both vectors
+    /// 
+    /// # Panics
+    /// Panics if the vectors have different lengths
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(5, vec![(0, 1), (2, 2), (4, 3)], true);
+    /// let y = SparseVector::from_pairs(5, vec![(2, 4), (3, 5), (4, 6)], true);
+    /// 
+    /// assert_eq!(x.dot(&y), 26);
+    /// ```
+    pub fn dot(&self, other: &Self) -> T {
+        if self.len != other.len {
+            panic!("Vectors must have the same length");
+        }
+        let mut result = T::default();
+        let mut a = 0;
+        let mut b = 0;
+        while a < self.indices.len() && b < other.indices.len() {
+            if self.indices[a] < other.indices[b] {
+                a += 1;
+            } else if self.indices[a] > other.indices[b] {
+                b += 1;
+            } else {
+                result += self.values[a] * other.values[b];
+                a += 1;
+                b += 1;
+            }
+        }
+        return result;
+    }
+    /// Dot product with a dense vector of the same length, ignoring their orientation
+    /// 
+    /// # Panics
+    /// Panics if the vectors have different lengths
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = SparseVector::from_pairs(3, vec![(0, 2), (2, 3)], true);
+    /// let y = Vector::new(vec![4, 5, 6], true);
+    /// 
+    /// assert_eq!(x.dot_dense(&y), 26);
+    /// ```
+    pub fn dot_dense(&self, other: &Vector<T>) -> T {
+        if self.len != other.len() {
+            panic!("Vectors must have the same length");
+        }
+        let mut result = T::default();
+        for (index, value) in self.iter() {
+            result += *value * other[index];
+        }
+        return result;
+    }
+    /// Inner product of a row vector and a column vector
+    /// 
+    /// Returns `None` unless `self` is a row, `other` is a column and both have the same length
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let row = SparseVector::from_pairs(3, vec![(1, 2)], false);
+    /// let column = SparseVector::from_pairs(3, vec![(1, 3)], true);
+    /// 
+    /// assert_eq!(row.inner(&column), Some(6));
+    /// assert_eq!(column.inner(&row), None);
+    /// ```
+    pub fn inner(&self, other: &Self) -> Option<T> {
+        if self.column_or_row || !other.column_or_row || self.len != other.len {
+            return None;
+        }
+        return Some(self.dot(other));
+    }
+    /// Inner product of a sparse row vector and a dense column vector
+    /// 
+    /// Returns `None` unless `self` is a row, `other` is a column and both have the same length
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let row = SparseVector::from_pairs(3, vec![(1, 2)], false);
+    /// let column = Vector::new(vec![1, 3, 5], true);
+    /// 
+    /// assert_eq!(row.inner_dense(&column), Some(6));
+    /// ```
+    pub fn inner_dense(&self, other: &Vector<T>) -> Option<T> {
+        if self.column_or_row || !other.is_column() || self.len != other.len() {
+            return None;
+        }
+        return Some(self.dot_dense(other));
+    }
+}
+
+impl<T: Default + PartialEq + Copy> SparseVector<T> {
+    fn merge<F: Fn(T, T) -> T>(&self, other: &Self, f: F) -> Self {
+        if self.len != other.len {
+            panic!("Vectors must have the same length");
+        }
+        let mut result = SparseVector::new(self.len, self.column_or_row);
+        let mut a = 0;
+        let mut b = 0;
+        while a < self.indices.len() || b < other.indices.len() {
+            let (index, value) = if b == other.indices.len() || (a < self.indices.len() && self.indices[a] < other.indices[b]) {
+                a += 1;
+                (self.indices[a - 1], f(self.values[a - 1], T::default()))
+            } else if a == self.indices.len() || self.indices[a] > other.indices[b] {
+                b += 1;
+                (other.indices[b - 1], f(T::default(), other.values[b - 1]))
+            } else {
+                a += 1;
+                b += 1;
+                (self.indices[a - 1], f(self.values[a - 1], other.values[b - 1]))
+            };
+            if value != T::default() {
+                result.indices.push(index);
+                result.values.push(value);
+            }
+        }
+        return result;
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<Vector<T>> for SparseVector<T> {
+    /// Convert a dense vector into a sparse vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = SparseVector::from(Vector::new(vec![0, 3], true));
+    /// 
+    /// assert_eq!(x.nnz(), 1);
+    /// ```
+    fn from(vector: Vector<T>) -> Self {
+        return SparseVector::from_dense(&vector);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<SparseVector<T>> for Vector<T> {
+    /// Convert a sparse vector into a dense vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::from(SparseVector::from_pairs(2, vec![(1, 3)], true));
+    /// 
+    /// assert_eq!(x, Vector::new(vec![0, 3], true));
+    /// ```
+    fn from(vector: SparseVector<T>) -> Self {
+        return vector.to_dense();
+    }
+}
+
+impl<T: Add<Output = T> + Default + PartialEq + Copy> Add for SparseVector<T> {
+    type Output = Self;
+
+    /// Add two sparse vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(4, vec![(0, 1), (2, 2)], true);
+    /// let y = SparseVector::from_pairs(4, vec![(2, -2), (3, 5)], true);
+    /// 
+    /// assert_eq!(x + y, SparseVector::from_pairs(4, vec![(0, 1), (3, 5)], true));
+    /// ```
+    fn add(self, other: Self) -> Self {
+        return self.merge(&other, |a, b| a + b);
+    }
+}
+
+impl<T: Sub<Output = T> + Default + PartialEq + Copy> Sub for SparseVector<T> {
+    type Output = Self;
+
+    /// Subtract two sparse vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(4, vec![(0, 1), (2, 2)], true);
+    /// let y = SparseVector::from_pairs(4, vec![(2, 2), (3, 5)], true);
+    /// 
+    /// assert_eq!(x - y, SparseVector::from_pairs(4, vec![(0, 1), (3, -5)], true));
+    /// ```
+    fn sub(self, other: Self) -> Self {
+        return self.merge(&other, |a, b| a - b);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> Mul for SparseVector<T> {
+    type Output = T;
+
+    /// Dot Product of a sparse row vector and a sparse column vector
+    /// 
+    /// # Panics
+    /// Panics if the vectors have different lengths, or if `self` isn't a row and `other` isn't a column
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(3, vec![(0, 1.0), (2, 2.0)], false);
+    /// let y = SparseVector::from_pairs(3, vec![(2, 3.0)], true);
+    /// 
+    /// assert_eq!(x * y, 6.0);
+    /// ```
+    fn mul(self, other: Self) -> Self::Output {
+        if self.len != other.len {
+            panic!("Vectors must have the same length");
+        }
+        match self.inner(&other) {
+            Some(result) => return result,
+            None => panic!("Can only multiply a row vector by a column vector"),
+        }
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> Mul<Vector<T>> for SparseVector<T> {
+    type Output = T;
+
+    /// Dot Product of a sparse row vector and a dense column vector
+    /// 
+    /// # Panics
+    /// Panics if the vectors have different lengths, or if `self` isn't a row and `other` isn't a column
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = SparseVector::from_pairs(3, vec![(0, 1.0), (2, 2.0)], false);
+    /// let y = Vector::new(vec![1.0, 2.0, 3.0], true);
+    /// 
+    /// assert_eq!(x * y, 7.0);
+    /// ```
+    fn mul(self, other: Vector<T>) -> Self::Output {
+        if self.len != other.len() {
+            panic!("Vectors must have the same length");
+        }
+        match self.inner_dense(&other) {
+            Some(result) => return result,
+            None => panic!("Can only multiply a row vector by a column vector"),
+        }
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy + Display> Display for SparseVector<T> {
+    /// Display the sparse vector the same way as the equivalent dense vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::SparseVector;
+    /// 
+    /// let x = SparseVector::from_pairs(4, vec![(1, 3), (3, 4)], false);
+    /// 
+    /// assert_eq!(format!("{}", x), "[ 0 3 0 4 ]");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return Display::fmt(&self.to_dense(), f);
+    }
+}
+
+/// Sparse Math Matrix in coordinate (COO) format
+/// 
+/// An unordered list of (row, column, value) triplets, which is the easiest format to assemble.
+/// Triplets for the same position are added together when converting to another format
+#[derive(Clone, PartialEq, Debug)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+impl<T> CooMatrix<T> {
+    /// Create an empty (all zero) CooMatrix with the given dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x: CooMatrix<f64> = CooMatrix::new(3, 4);
+    /// 
+    /// assert_eq!((x.rows(), x.cols(), x.nnz()), (3, 4, 0));
+    /// ```
+    pub fn new(rows: usize, cols: usize) -> Self {
+        return CooMatrix {
+            rows,
+            cols,
+            entries: vec![],
+        };
+    }
+    /// Create a CooMatrix from its dimensions and (row, column, value) triplets in any order
+    /// 
+    /// # Panics
+    /// Panics if a position is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 3), (1, 0, 4)]);
+    /// 
+    /// assert_eq!(x.nnz(), 2);
+    /// ```
+    pub fn from_triplets(rows: usize, cols: usize, triplets: Vec<(usize, usize, T)>) -> Self {
+        let mut result = CooMatrix::new(rows, cols);
+        for (row, col, value) in triplets {
+            result.push(row, col, value);
+        }
+        return result;
+    }
+    /// Add a triplet to the matrix
+    /// 
+    /// # Panics
+    /// Panics if the position is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let mut x = CooMatrix::new(2, 2);
+    /// 
+    /// x.push(0, 0, 1.0);
+    /// x.push(0, 0, 2.0);
+    /// 
+    /// assert_eq!(x.to_csr().get(0, 0), 3.0);
+    /// ```
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        if row >= self.rows || col >= self.cols {
+            panic!("Position ({}, {}) is out of bounds for a {}x{} sparse matrix", row, col, self.rows, self.cols);
+        }
+        self.entries.push((row, col, value));
+    }
+    /// The number of rows in the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// assert_eq!(CooMatrix::<i32>::new(3, 4).rows(), 3);
+    /// ```
+    pub fn rows(&self) -> usize {
+        return self.rows;
+    }
+    /// The number of columns in the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// assert_eq!(CooMatrix::<i32>::new(3, 4).cols(), 4);
+    /// ```
+    pub fn cols(&self) -> usize {
+        return self.cols;
+    }
+    /// The number of stored triplets, counting duplicates separately
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (0, 0, 2)]);
+    /// 
+    /// assert_eq!(x.nnz(), 2);
+    /// ```
+    pub fn nnz(&self) -> usize {
+        return self.entries.len();
+    }
+    /// The stored (row, column, value) triplets, in the order they were added
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(1, 0, 5)]);
+    /// 
+    /// assert_eq!(x.triplets(), &[(1, 0, 5)]);
+    /// ```
+    pub fn triplets(&self) -> &[(usize, usize, T)] {
+        return &self.entries;
+    }
+}
+
+impl<T: Copy> CooMatrix<T> {
+    /// The transpose of the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(1, 2, 5)]);
+    /// 
+    /// assert_eq!(x.transpose(), CooMatrix::from_triplets(3, 2, vec![(2, 1, 5)]));
+    /// ```
+    pub fn transpose(&self) -> Self {
+        return CooMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            entries: self.entries.iter().map(|&(row, col, value)| (col, row, value)).collect(),
+        };
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> CooMatrix<T> {
+    /// Create a CooMatrix from the non-zero elements of a dense matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = CooMatrix::from_dense(&Matrix::new(2, 2, vec![0, 1, 2, 0]));
+    /// 
+    /// assert_eq!(x.triplets(), &[(0, 1, 1), (1, 0, 2)]);
+    /// ```
+    pub fn from_dense(matrix: &Matrix<T>) -> Self {
+        let mut result = CooMatrix::new(matrix.rows(), matrix.cols());
+        for row in 0..matrix.rows() {
+            for col in 0..matrix.cols() {
+                if matrix[(row, col)] != T::default() {
+                    result.entries.push((row, col, matrix[(row, col)]));
+                }
+            }
+        }
+        return result;
+    }
+    /// Convert the matrix into a dense matrix, adding duplicate triplets together
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 1), (0, 1, 2)]);
+    /// 
+    /// assert_eq!(x.to_dense(), Matrix::new(2, 2, vec![0, 3, 0, 0]));
+    /// ```
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut result = Matrix::zeros(self.rows, self.cols);
+        for &(row, col, value) in &self.entries {
+            result[(row, col)] += value;
+        }
+        return result;
+    }
+    /// Convert the matrix into compressed sparse row format
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(1, 2, 1), (0, 1, 2), (1, 0, 3)]).to_csr();
+    /// 
+    /// assert_eq!(x.row_offsets(), &[0, 1, 3]);
+    /// assert_eq!(x.col_indices(), &[1, 0, 2]);
+    /// assert_eq!(x.values(), &[2, 3, 1]);
+    /// ```
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let mut entries = self.entries.clone();
+        entries.sort_by_key(|&(row, col, _)| (row, col));
+        return CsrMatrix::from_sorted(self.rows, self.cols, entries);
+    }
+    /// Convert the matrix into compressed sparse column format
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(1, 2, 1), (0, 1, 2), (1, 0, 3)]).to_csc();
+    /// 
+    /// assert_eq!(x.col_offsets(), &[0, 1, 2, 3]);
+    /// assert_eq!(x.row_indices(), &[1, 0, 1]);
+    /// assert_eq!(x.values(), &[3, 2, 1]);
+    /// ```
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        return CscMatrix { columns: self.transpose().to_csr() };
+    }
+}
+
+/// Sparse Math Matrix in compressed sparse row (CSR) format
+/// 
+/// The stored elements are kept row by row, sorted by column within each row,
+/// which makes products with a column vector fast
+#[derive(Clone, PartialEq, Debug)]
+pub struct CsrMatrix<T> {
+    rows: usize,
+    cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// The number of rows in the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// assert_eq!(CooMatrix::<i32>::new(3, 4).to_csr().rows(), 3);
+    /// ```
+    pub fn rows(&self) -> usize {
+        return self.rows;
+    }
+    /// The number of columns in the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// assert_eq!(CooMatrix::<i32>::new(3, 4).to_csr().cols(), 4);
+    /// ```
+    pub fn cols(&self) -> usize {
+        return self.cols;
+    }
+    /// The number of stored (non-zero) elements
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (0, 0, 2), (1, 1, 0)]).to_csr();
+    /// 
+    /// assert_eq!(x.nnz(), 1);
+    /// ```
+    pub fn nnz(&self) -> usize {
+        return self.values.len();
+    }
+    /// Where each row starts in [`CsrMatrix::col_indices`] and [`CsrMatrix::values`], followed by [`CsrMatrix::nnz`]
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(3, 2, vec![(0, 0, 1), (2, 1, 2)]).to_csr();
+    /// 
+    /// assert_eq!(x.row_offsets(), &[0, 1, 1, 2]);
+    /// ```
+    pub fn row_offsets(&self) -> &[usize] {
+        return &self.row_offsets;
+    }
+    /// The column of each stored element
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(3, 2, vec![(0, 0, 1), (2, 1, 2)]).to_csr();
+    /// 
+    /// assert_eq!(x.col_indices(), &[0, 1]);
+    /// ```
+    pub fn col_indices(&self) -> &[usize] {
+        return &self.col_indices;
+    }
+    /// The stored elements, row by row
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(3, 2, vec![(2, 1, 2), (0, 0, 1)]).to_csr();
+    /// 
+    /// assert_eq!(x.values(), &[1, 2]);
+    /// ```
+    pub fn values(&self) -> &[T] {
+        return &self.values;
+    }
+    /// Iterate over the stored elements as (row, column, value) triplets
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(1, 0, 2), (0, 1, 1)]).to_csr();
+    /// 
+    /// assert_eq!(x.iter().collect::<Vec<_>>(), vec![(0, 1, &1), (1, 0, &2)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        return (0..self.rows).flat_map(move |row| {
+            (self.row_offsets[row]..self.row_offsets[row + 1]).map(move |position| (row, self.col_indices[position], &self.values[position]))
+        });
+    }
+}
+
+impl<T: Copy> CsrMatrix<T> {
+    /// The transpose of the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(0, 2, 1), (1, 0, 2)]).to_csr();
+    /// 
+    /// assert_eq!(x.transpose(), CooMatrix::from_triplets(3, 2, vec![(2, 0, 1), (0, 1, 2)]).to_csr());
+    /// ```
+    pub fn transpose(&self) -> Self {
+        // Counting sort by column, visiting rows in order keeps each new row sorted
+        let mut row_offsets = vec![0; self.cols + 1];
+        for col in &self.col_indices {
+            row_offsets[col + 1] += 1;
+        }
+        for col in 0..self.cols {
+            row_offsets[col + 1] += row_offsets[col];
+        }
+        let mut next = row_offsets.clone();
+        let mut col_indices = vec![0; self.nnz()];
+        let mut values = self.values.clone();
+        for (row, col, value) in self.iter() {
+            col_indices[next[col]] = row;
+            values[next[col]] = *value;
+            next[col] += 1;
+        }
+        return CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets,
+            col_indices,
+            values,
+        };
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> CsrMatrix<T> {
+    /// Create a CsrMatrix from the non-zero elements of a dense matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CsrMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = CsrMatrix::from_dense(&Matrix::new(2, 2, vec![0, 1, 2, 0]));
+    /// 
+    /// assert_eq!(x.values(), &[1, 2]);
+    /// ```
+    pub fn from_dense(matrix: &Matrix<T>) -> Self {
+        return CooMatrix::from_dense(matrix).to_csr();
+    }
+    /// Convert the matrix into a dense matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CsrMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![0, 1, 0, 2, 0, 3]);
+    /// 
+    /// assert_eq!(CsrMatrix::from_dense(&x).to_dense(), x);
+    /// ```
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut result = Matrix::zeros(self.rows, self.cols);
+        for (row, col, value) in self.iter() {
+            result[(row, col)] = *value;
+        }
+        return result;
+    }
+    /// Get an element of the matrix, which is zero if it isn't stored
+    /// 
+    /// # Panics
+    /// Panics if the position is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(1, 0, 5)]).to_csr();
+    /// 
+    /// assert_eq!(x.get(1, 0), 5);
+    /// assert_eq!(x.get(0, 0), 0);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> T {
+        if row >= self.rows || col >= self.cols {
+            panic!("Position ({}, {}) is out of bounds for a {}x{} sparse matrix", row, col, self.rows, self.cols);
+        }
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        return match self.col_indices[range.clone()].binary_search(&col) {
+            Ok(position) => self.values[range.start + position],
+            Err(_) => T::default(),
+        };
+    }
+    /// Get a copy of a row of the matrix as a sparse row vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::{CooMatrix, SparseVector};
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(1, 2, 5)]).to_csr();
+    /// 
+    /// assert_eq!(x.row(1), SparseVector::from_pairs(3, vec![(2, 5)], false));
+    /// ```
+    pub fn row(&self, row: usize) -> SparseVector<T> {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        return SparseVector {
+            len: self.cols,
+            indices: self.col_indices[range.clone()].to_vec(),
+            values: self.values[range].to_vec(),
+            column_or_row: false,
+        };
+    }
+    /// Convert the matrix into coordinate format
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(1, 0, 2), (0, 1, 1)]);
+    /// 
+    /// assert_eq!(x.to_csr().to_coo().triplets(), &[(0, 1, 1), (1, 0, 2)]);
+    /// ```
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        return CooMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            entries: self.iter().map(|(row, col, value)| (row, col, *value)).collect(),
+        };
+    }
+    /// Convert the matrix into compressed sparse column format
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(1, 0, 2), (0, 1, 1)]);
+    /// 
+    /// assert_eq!(x.to_csr().to_csc(), x.to_csc());
+    /// ```
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        return CscMatrix { columns: self.transpose() };
+    }
+
+    // Build from triplets sorted by row then column, adding duplicates together and dropping zeros
+    fn from_sorted(rows: usize, cols: usize, entries: Vec<(usize, usize, T)>) -> Self {
+        let mut row_offsets = Vec::with_capacity(rows + 1);
+        let mut col_indices: Vec<usize> = Vec::with_capacity(entries.len());
+        let mut values: Vec<T> = Vec::with_capacity(entries.len());
+        let mut entries = entries.into_iter().peekable();
+        row_offsets.push(0);
+        for row in 0..rows {
+            while let Some(&(_, col, value)) = entries.peek().filter(|entry| entry.0 == row) {
+                entries.next();
+                if col_indices.len() > row_offsets[row] && col_indices.last() == Some(&col) {
+                    *values.last_mut().unwrap() += value;
+                } else {
+                    col_indices.push(col);
+                    values.push(value);
+                }
+            }
+            // Drop the zeros of this row, including sums that cancelled out
+            let mut kept = row_offsets[row];
+            for position in row_offsets[row]..values.len() {
+                if values[position] != T::default() {
+                    col_indices[kept] = col_indices[position];
+                    values[kept] = values[position];
+                    kept += 1;
+                }
+            }
+            col_indices.truncate(kept);
+            values.truncate(kept);
+            row_offsets.push(kept);
+        }
+        return CsrMatrix {
+            rows,
+            cols,
+            row_offsets,
+            col_indices,
+            values,
+        };
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> CsrMatrix<T> {
+    /// Multiply the matrix by a dense column vector
+    /// 
+    /// # Panics
+    /// Panics if the vector isn't a column, or doesn't have an element for every column of the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(0, 0, 2), (1, 2, 3)]).to_csr();
+    /// 
+    /// assert_eq!(x.mul_dense(&Vector::new(vec![1, 2, 3], true)), Vector::new(vec![2, 9], true));
+    /// ```
+    pub fn mul_dense(&self, vector: &Vector<T>) -> Vector<T> {
+        if !vector.is_column() || vector.len() != self.cols {
+            panic!("Can only multiply a {}x{} matrix by a column vector of length {}", self.rows, self.cols, self.cols);
+        }
+        let mut result = vec![T::default(); self.rows];
+        for (row, col, value) in self.iter() {
+            result[row] += *value * vector[col];
+        }
+        return Vector::new(result, true);
+    }
+    /// Multiply two sparse matrices
+    /// 
+    /// This only visits the products of stored elements that meet
+    /// 
+    /// # Panics
+    /// Panics if the number of columns of `self` isn't the number of rows of `other`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CsrMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![1, 2, 0, 3]);
+    /// let b = Matrix::new(2, 2, vec![0, 1, 4, 0]);
+    /// let product = CsrMatrix::from_dense(&a).mul_sparse(&CsrMatrix::from_dense(&b));
+    /// 
+    /// assert_eq!(product.to_dense(), a * b);
+    /// ```
+    pub fn mul_sparse(&self, other: &Self) -> Self {
+        if self.cols != other.rows {
+            panic!("Can't multiply a {}x{} matrix by a {}x{} matrix", self.rows, self.cols, other.rows, other.cols);
+        }
+        let mut accumulator = vec![T::default(); other.cols];
+        let mut occupied = vec![false; other.cols];
+        let mut touched = vec![];
+        let mut row_offsets = Vec::with_capacity(self.rows + 1);
+        let mut col_indices = vec![];
+        let mut values = vec![];
+        row_offsets.push(0);
+        for row in 0..self.rows {
+            for position in self.row_offsets[row]..self.row_offsets[row + 1] {
+                let (middle, value) = (self.col_indices[position], self.values[position]);
+                for other_position in other.row_offsets[middle]..other.row_offsets[middle + 1] {
+                    let col = other.col_indices[other_position];
+                    if !occupied[col] {
+                        occupied[col] = true;
+                        touched.push(col);
+                    }
+                    accumulator[col] += value * other.values[other_position];
+                }
+            }
+            touched.sort_unstable();
+            for col in touched.drain(..) {
+                if accumulator[col] != T::default() {
+                    col_indices.push(col);
+                    values.push(accumulator[col]);
+                }
+                accumulator[col] = T::default();
+                occupied[col] = false;
+            }
+            row_offsets.push(values.len());
+        }
+        return CsrMatrix {
+            rows: self.rows,
+            cols: other.cols,
+            row_offsets,
+            col_indices,
+            values,
+        };
+    }
+}
+
+impl<T: Default + PartialEq + Copy> CsrMatrix<T> {
+    fn merge<F: Fn(T, T) -> T>(&self, other: &Self, f: F) -> Self {
+        if self.rows != other.rows || self.cols != other.cols {
+            panic!("Matrices must have the same dimensions");
+        }
+        let mut row_offsets = Vec::with_capacity(self.rows + 1);
+        let mut col_indices = vec![];
+        let mut values = vec![];
+        row_offsets.push(0);
+        for row in 0..self.rows {
+            let (mut a, a_end) = (self.row_offsets[row], self.row_offsets[row + 1]);
+            let (mut b, b_end) = (other.row_offsets[row], other.row_offsets[row + 1]);
+            while a < a_end || b < b_end {
+                let (col, value) = if b == b_end || (a < a_end && self.col_indices[a] < other.col_indices[b]) {
+                    a += 1;
+                    (self.col_indices[a - 1], f(self.values[a - 1], T::default()))
+                } else if a == a_end || self.col_indices[a] > other.col_indices[b] {
+                    b += 1;
+                    (other.col_indices[b - 1], f(T::default(), other.values[b - 1]))
+                } else {
+                    a += 1;
+                    b += 1;
+                    (self.col_indices[a - 1], f(self.values[a - 1], other.values[b - 1]))
+                };
+                if value != T::default() {
+                    col_indices.push(col);
+                    values.push(value);
+                }
+            }
+            row_offsets.push(values.len());
+        }
+        return CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        };
+    }
+}
+
+/// Sparse Math Matrix in compressed sparse column (CSC) format
+/// 
+/// The stored elements are kept column by column, sorted by row within each column,
+/// which makes slicing columns fast
+#[derive(Clone, PartialEq, Debug)]
+pub struct CscMatrix<T> {
+    // The CSR form of the transpose, whose rows are the columns of this matrix
+    columns: CsrMatrix<T>,
+}
+
+impl<T> CscMatrix<T> {
+    /// The number of rows in the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// assert_eq!(CooMatrix::<i32>::new(3, 4).to_csc().rows(), 3);
+    /// ```
+    pub fn rows(&self) -> usize {
+        return self.columns.cols;
+    }
+    /// The number of columns in the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// assert_eq!(CooMatrix::<i32>::new(3, 4).to_csc().cols(), 4);
+    /// ```
+    pub fn cols(&self) -> usize {
+        return self.columns.rows;
+    }
+    /// The number of stored (non-zero) elements
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (1, 1, 2)]).to_csc();
+    /// 
+    /// assert_eq!(x.nnz(), 2);
+    /// ```
+    pub fn nnz(&self) -> usize {
+        return self.columns.nnz();
+    }
+    /// Where each column starts in [`CscMatrix::row_indices`] and [`CscMatrix::values`], followed by [`CscMatrix::nnz`]
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(0, 0, 1), (1, 2, 2)]).to_csc();
+    /// 
+    /// assert_eq!(x.col_offsets(), &[0, 1, 1, 2]);
+    /// ```
+    pub fn col_offsets(&self) -> &[usize] {
+        return &self.columns.row_offsets;
+    }
+    /// The row of each stored element
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(0, 0, 1), (1, 2, 2)]).to_csc();
+    /// 
+    /// assert_eq!(x.row_indices(), &[0, 1]);
+    /// ```
+    pub fn row_indices(&self) -> &[usize] {
+        return &self.columns.col_indices;
+    }
+    /// The stored elements, column by column
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 1), (1, 0, 2)]).to_csc();
+    /// 
+    /// assert_eq!(x.values(), &[2, 1]);
+    /// ```
+    pub fn values(&self) -> &[T] {
+        return &self.columns.values;
+    }
+    /// Iterate over the stored elements as (row, column, value) triplets, column by column
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 1), (1, 0, 2)]).to_csc();
+    /// 
+    /// assert_eq!(x.iter().collect::<Vec<_>>(), vec![(1, 0, &2), (0, 1, &1)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        return self.columns.iter().map(|(col, row, value)| (row, col, value));
+    }
+}
+
+impl<T: Copy> CscMatrix<T> {
+    /// The transpose of the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(0, 2, 1), (1, 0, 2)]);
+    /// 
+    /// assert_eq!(x.to_csc().transpose(), x.transpose().to_csc());
+    /// ```
+    pub fn transpose(&self) -> Self {
+        return CscMatrix { columns: self.columns.transpose() };
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> CscMatrix<T> {
+    /// Create a CscMatrix from the non-zero elements of a dense matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CscMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = CscMatrix::from_dense(&Matrix::new(2, 2, vec![0, 1, 2, 0]));
+    /// 
+    /// assert_eq!(x.values(), &[2, 1]);
+    /// ```
+    pub fn from_dense(matrix: &Matrix<T>) -> Self {
+        return CooMatrix::from_dense(matrix).to_csc();
+    }
+    /// Convert the matrix into a dense matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CscMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![0, 1, 0, 2, 0, 3]);
+    /// 
+    /// assert_eq!(CscMatrix::from_dense(&x).to_dense(), x);
+    /// ```
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut result = Matrix::zeros(self.rows(), self.cols());
+        for (row, col, value) in self.iter() {
+            result[(row, col)] = *value;
+        }
+        return result;
+    }
+    /// Get an element of the matrix, which is zero if it isn't stored
+    /// 
+    /// # Panics
+    /// Panics if the position is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(1, 0, 5)]).to_csc();
+    /// 
+    /// assert_eq!(x.get(1, 0), 5);
+    /// assert_eq!(x.get(0, 1), 0);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> T {
+        if row >= self.rows() || col >= self.cols() {
+            panic!("Position ({}, {}) is out of bounds for a {}x{} sparse matrix", row, col, self.rows(), self.cols());
+        }
+        return self.columns.get(col, row);
+    }
+    /// Get a copy of a column of the matrix as a sparse column vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::{CooMatrix, SparseVector};
+    /// 
+    /// let x = CooMatrix::from_triplets(3, 2, vec![(2, 1, 5)]).to_csc();
+    /// 
+    /// assert_eq!(x.col(1), SparseVector::from_pairs(3, vec![(2, 5)], true));
+    /// ```
+    pub fn col(&self, col: usize) -> SparseVector<T> {
+        let mut result = self.columns.row(col);
+        result.transpose();
+        return result;
+    }
+    /// Convert the matrix into coordinate format
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 1), (1, 0, 2)]);
+    /// 
+    /// assert_eq!(x.to_csc().to_coo().triplets(), &[(1, 0, 2), (0, 1, 1)]);
+    /// ```
+    pub fn to_coo(&self) -> CooMatrix<T> {
+        return CooMatrix {
+            rows: self.rows(),
+            cols: self.cols(),
+            entries: self.iter().map(|(row, col, value)| (row, col, *value)).collect(),
+        };
+    }
+    /// Convert the matrix into compressed sparse row format
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(1, 0, 2), (0, 1, 1)]);
+    /// 
+    /// assert_eq!(x.to_csc().to_csr(), x.to_csr());
+    /// ```
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        return self.columns.transpose();
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> CscMatrix<T> {
+    /// Multiply the matrix by a dense column vector
+    /// 
+    /// # Panics
+    /// Panics if the vector isn't a column, or doesn't have an element for every column of the matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 3, vec![(0, 0, 2), (1, 2, 3)]).to_csc();
+    /// 
+    /// assert_eq!(x.mul_dense(&Vector::new(vec![1, 2, 3], true)), Vector::new(vec![2, 9], true));
+    /// ```
+    pub fn mul_dense(&self, vector: &Vector<T>) -> Vector<T> {
+        if !vector.is_column() || vector.len() != self.cols() {
+            panic!("Can only multiply a {}x{} matrix by a column vector of length {}", self.rows(), self.cols(), self.cols());
+        }
+        let mut result = vec![T::default(); self.rows()];
+        for (row, col, value) in self.iter() {
+            result[row] += *value * vector[col];
+        }
+        return Vector::new(result, true);
+    }
+    /// Multiply two sparse matrices
+    /// 
+    /// # Panics
+    /// Panics if the number of columns of `self` isn't the number of rows of `other`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CscMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![1, 2, 0, 3]);
+    /// let b = Matrix::new(2, 2, vec![0, 1, 4, 0]);
+    /// let product = CscMatrix::from_dense(&a).mul_sparse(&CscMatrix::from_dense(&b));
+    /// 
+    /// assert_eq!(product.to_dense(), a * b);
+    /// ```
+    pub fn mul_sparse(&self, other: &Self) -> Self {
+        if self.cols() != other.rows() {
+            panic!("Can't multiply a {}x{} matrix by a {}x{} matrix", self.rows(), self.cols(), other.rows(), other.cols());
+        }
+        // (AB)ᵀ = BᵀAᵀ, and the CSR form of a transpose is the CSC form of the matrix
+        return CscMatrix { columns: other.columns.mul_sparse(&self.columns) };
+    }
+}
+
+impl<T: Add<Output = T> + Default + PartialEq + Copy> Add for CsrMatrix<T> {
+    type Output = Self;
+
+    /// Add two sparse matrices
+    /// 
+    /// # Panics
+    /// Panics if the matrices have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (1, 1, 2)]).to_csr();
+    /// let y = CooMatrix::from_triplets(2, 2, vec![(1, 1, -2), (0, 1, 5)]).to_csr();
+    /// 
+    /// assert_eq!(x + y, CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (0, 1, 5)]).to_csr());
+    /// ```
+    fn add(self, other: Self) -> Self {
+        return self.merge(&other, |a, b| a + b);
+    }
+}
+
+impl<T: Sub<Output = T> + Default + PartialEq + Copy> Sub for CsrMatrix<T> {
+    type Output = Self;
+
+    /// Subtract two sparse matrices
+    /// 
+    /// # Panics
+    /// Panics if the matrices have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (1, 1, 2)]).to_csr();
+    /// let y = CooMatrix::from_triplets(2, 2, vec![(1, 1, 2), (0, 1, 5)]).to_csr();
+    /// 
+    /// assert_eq!(x - y, CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (0, 1, -5)]).to_csr());
+    /// ```
+    fn sub(self, other: Self) -> Self {
+        return self.merge(&other, |a, b| a - b);
+    }
+}
+
+impl<T: Add<Output = T> + Default + PartialEq + Copy> Add for CscMatrix<T> {
+    type Output = Self;
+
+    /// Add two sparse matrices
+    /// 
+    /// # Panics
+    /// Panics if the matrices have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (1, 1, 2)]).to_csc();
+    /// let y = CooMatrix::from_triplets(2, 2, vec![(1, 1, -2), (0, 1, 5)]).to_csc();
+    /// 
+    /// assert_eq!(x + y, CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (0, 1, 5)]).to_csc());
+    /// ```
+    fn add(self, other: Self) -> Self {
+        return CscMatrix { columns: self.columns + other.columns };
+    }
+}
+
+impl<T: Sub<Output = T> + Default + PartialEq + Copy> Sub for CscMatrix<T> {
+    type Output = Self;
+
+    /// Subtract two sparse matrices
+    /// 
+    /// # Panics
+    /// Panics if the matrices have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (1, 1, 2)]).to_csc();
+    /// let y = CooMatrix::from_triplets(2, 2, vec![(1, 1, 2), (0, 1, 5)]).to_csc();
+    /// 
+    /// assert_eq!(x - y, CooMatrix::from_triplets(2, 2, vec![(0, 0, 1), (0, 1, -5)]).to_csc());
+    /// ```
+    fn sub(self, other: Self) -> Self {
+        return CscMatrix { columns: self.columns - other.columns };
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> Mul for CsrMatrix<T> {
+    type Output = Self;
+
+    /// Multiply two sparse matrices, see [`CsrMatrix::mul_sparse`]
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 2)]).to_csr();
+    /// let y = CooMatrix::from_triplets(2, 2, vec![(1, 0, 3)]).to_csr();
+    /// 
+    /// assert_eq!(x * y, CooMatrix::from_triplets(2, 2, vec![(0, 0, 6)]).to_csr());
+    /// ```
+    fn mul(self, other: Self) -> Self {
+        return self.mul_sparse(&other);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> Mul<Vector<T>> for CsrMatrix<T> {
+    type Output = Vector<T>;
+
+    /// Multiply the matrix by a dense column vector, see [`CsrMatrix::mul_dense`]
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 2)]).to_csr();
+    /// 
+    /// assert_eq!(x * Vector::new(vec![1, 3], true), Vector::new(vec![6, 0], true));
+    /// ```
+    fn mul(self, other: Vector<T>) -> Vector<T> {
+        return self.mul_dense(&other);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> Mul for CscMatrix<T> {
+    type Output = Self;
+
+    /// Multiply two sparse matrices, see [`CscMatrix::mul_sparse`]
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 2)]).to_csc();
+    /// let y = CooMatrix::from_triplets(2, 2, vec![(1, 0, 3)]).to_csc();
+    /// 
+    /// assert_eq!(x * y, CooMatrix::from_triplets(2, 2, vec![(0, 0, 6)]).to_csc());
+    /// ```
+    fn mul(self, other: Self) -> Self {
+        return self.mul_sparse(&other);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Mul<Output = T> + Copy> Mul<Vector<T>> for CscMatrix<T> {
+    type Output = Vector<T>;
+
+    /// Multiply the matrix by a dense column vector, see [`CscMatrix::mul_dense`]
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CooMatrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = CooMatrix::from_triplets(2, 2, vec![(0, 1, 2)]).to_csc();
+    /// 
+    /// assert_eq!(x * Vector::new(vec![1, 3], true), Vector::new(vec![6, 0], true));
+    /// ```
+    fn mul(self, other: Vector<T>) -> Vector<T> {
+        return self.mul_dense(&other);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<CooMatrix<T>> for CsrMatrix<T> {
+    /// Convert a coordinate matrix into compressed sparse row format
+    fn from(matrix: CooMatrix<T>) -> Self {
+        return matrix.to_csr();
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<CooMatrix<T>> for CscMatrix<T> {
+    /// Convert a coordinate matrix into compressed sparse column format
+    fn from(matrix: CooMatrix<T>) -> Self {
+        return matrix.to_csc();
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<CscMatrix<T>> for CsrMatrix<T> {
+    /// Convert a compressed sparse column matrix into compressed sparse row format
+    fn from(matrix: CscMatrix<T>) -> Self {
+        return matrix.to_csr();
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<CsrMatrix<T>> for CscMatrix<T> {
+    /// Convert a compressed sparse row matrix into compressed sparse column format
+    fn from(matrix: CsrMatrix<T>) -> Self {
+        return matrix.to_csc();
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<Matrix<T>> for CsrMatrix<T> {
+    /// Convert a dense matrix into compressed sparse row format
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CsrMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![0, 1, 0, 0]);
+    /// 
+    /// assert_eq!(Matrix::from(CsrMatrix::from(x.clone())), x);
+    /// ```
+    fn from(matrix: Matrix<T>) -> Self {
+        return CsrMatrix::from_dense(&matrix);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<CsrMatrix<T>> for Matrix<T> {
+    /// Convert a compressed sparse row matrix into a dense matrix
+    fn from(matrix: CsrMatrix<T>) -> Self {
+        return matrix.to_dense();
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<Matrix<T>> for CscMatrix<T> {
+    /// Convert a dense matrix into compressed sparse column format
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::sparse::CscMatrix;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![0, 1, 0, 0]);
+    /// 
+    /// assert_eq!(Matrix::from(CscMatrix::from(x.clone())), x);
+    /// ```
+    fn from(matrix: Matrix<T>) -> Self {
+        return CscMatrix::from_dense(&matrix);
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Copy> From<CscMatrix<T>> for Matrix<T> {
+    /// Convert a compressed sparse column matrix into a dense matrix
+    fn from(matrix: CscMatrix<T>) -> Self {
+        return matrix.to_dense();
+    }
+}
@@ -0,0 +1,529 @@
+/*!
+# SVD
+Singular value decomposition and the tools built on it
+
+# Examples
+```rust
+use lemonmath::matrices::Matrix;
+use lemonmath::vectors::Vector;
+
+let a = Matrix::new(3, 2, vec![3.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+let svd = a.svd().unwrap();
+
+assert_eq!(svd.singular_values(), &Vector::new(vec![3.0, 2.0], true));
+
+// A = UΣVᵀ
+let product = svd.u().clone() * svd.sigma() * svd.v().transpose();
+assert!((product - a.clone()).content().iter().all(|x| x.abs() < 1e-12));
+
+// Rank, condition number, null space and pseudoinverse
+let b = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+
+assert_eq!(b.rank(1e-10), Ok(1));
+assert_eq!(a.condition_number(), Ok(1.5));
+assert_eq!(b.null_space(1e-10).unwrap().len(), 1);
+
+let pinv = a.pseudo_inverse().unwrap();
+let expected = Matrix::new(2, 3, vec![1.0 / 3.0, 0.0, 0.0, 0.0, 0.5, 0.0]);
+assert!((pinv - expected).content().iter().all(|x| x.abs() < 1e-12));
+```
+*/
+
+use crate::matrices::{Matrix, MatrixError};
+use crate::vectors::Vector;
+
+// The most QR steps spent on a single singular value
+const MAX_ITERATIONS: usize = 100;
+
+#[test]
+fn svd_test() {
+    let a = Matrix::new(4, 3, vec![
+        2.0, -1.0, 0.5,
+        1.0, 3.0, -2.0,
+        0.0, 4.0, 1.0,
+        -3.0, 1.0, 2.0,
+    ]);
+    for matrix in [a.clone(), a.transpose()] {
+        let svd = matrix.svd().unwrap();
+        let product = svd.u().clone() * svd.sigma() * svd.v().transpose();
+        assert!((product - matrix.clone()).content().iter().all(|x| x.abs() < 1e-12));
+        let orthogonal = svd.v().transpose() * svd.v().clone();
+        assert!((orthogonal - Matrix::identity(svd.v().rows())).content().iter().all(|x| x.abs() < 1e-12));
+        assert!(svd.singular_values().windows(2).all(|x| x[0] >= x[1]));
+    }
+    // Zeros on the diagonal of the bidiagonal form have to be split off before any QR steps
+    let shift = Matrix::new(3, 3, vec![0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    let svd = shift.svd().unwrap();
+    assert_eq!(svd.singular_values(), &Vector::new(vec![2.0, 1.0, 0.0], true));
+    let product = svd.u().clone() * svd.sigma() * svd.v().transpose();
+    assert!((product - shift).content().iter().all(|x| x.abs() < 1e-12));
+}
+
+/// A singular value decomposition, where `A = UΣVᵀ`
+/// 
+/// For an `m`x`n` matrix, `U` is `m`x`n`, there are `n` singular values in descending order,
+/// and `V` is an `n`x`n` orthogonal matrix. Only the first `min(m, n)` columns of `U` are orthonormal,
+/// as a wide matrix has too few rows for more, and the columns after them go with zero singular values
+#[derive(Clone, PartialEq, Debug)]
+pub struct SVD {
+    u: Matrix<f64>,
+    singular_values: Vector<f64>,
+    v: Matrix<f64>,
+    rows: usize,
+}
+
+impl Matrix<f64> {
+    /// Singular value decomposition using Golub–Kahan bidiagonalization and implicit shifted QR
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NoConvergence`] if a singular value doesn't converge
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 3, vec![1.0, 0.0, 1.0, 0.0, 1.0, 0.0]);
+    /// let svd = a.svd().unwrap();
+    /// 
+    /// assert!((svd.singular_values()[0] - 2.0f64.sqrt()).abs() < 1e-12);
+    /// assert!((svd.singular_values()[1] - 1.0).abs() < 1e-12);
+    /// assert!(svd.singular_values()[2].abs() < 1e-12);
+    /// ```
+    pub fn svd(&self) -> Result<SVD, MatrixError> {
+        let (m, n) = (self.rows(), self.cols());
+        // Pad short matrices with zero rows so V always covers the whole domain
+        let padded = Matrix::from_fn(m.max(n), n, |row, col| if row < m { self[(row, col)] } else { 0.0 });
+        let SVD { u, singular_values: w, v, .. } = golub_kahan(&padded)?;
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|x, y| w[*y].total_cmp(&w[*x]));
+        let u_matrix = Matrix::from_fn(m, n, |row, col| u[(row, order[col])]);
+        let v_matrix = Matrix::from_fn(n, n, |row, col| v[(row, order[col])]);
+        return Ok(SVD {
+            u: u_matrix,
+            singular_values: Vector::new(order.iter().map(|x| w[*x]).collect(), true),
+            v: v_matrix,
+            rows: m
+        });
+    }
+    /// The Moore–Penrose pseudoinverse, ignoring singular values too small to tell apart from zero
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NoConvergence`] if the SVD doesn't converge
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![1.0, 1.0, 1.0, 1.0]);
+    /// let pinv = a.pseudo_inverse().unwrap();
+    /// 
+    /// assert!(pinv.content().iter().all(|x| (x - 0.25).abs() < 1e-12));
+    /// ```
+    pub fn pseudo_inverse(&self) -> Result<Matrix<f64>, MatrixError> {
+        let svd = self.svd()?;
+        return Ok(svd.pseudo_inverse(svd.default_tolerance()));
+    }
+    /// The 2-norm condition number, the ratio of the largest and smallest singular values
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NoConvergence`] if the SVD doesn't converge
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// assert_eq!(Matrix::new(2, 2, vec![4.0, 0.0, 0.0, 0.5]).condition_number(), Ok(8.0));
+    /// assert_eq!(Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 0.0]).condition_number(), Ok(f64::INFINITY));
+    /// ```
+    pub fn condition_number(&self) -> Result<f64, MatrixError> {
+        return Ok(self.svd()?.condition_number());
+    }
+    /// The number of singular values larger than `tolerance`
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NoConvergence`] if the SVD doesn't converge
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// 
+    /// assert_eq!(a.rank(1e-10), Ok(2));
+    /// ```
+    pub fn rank(&self, tolerance: f64) -> Result<usize, MatrixError> {
+        return Ok(self.svd()?.rank(tolerance));
+    }
+    /// An orthonormal basis of the null space, from the singular values no larger than `tolerance`
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::NoConvergence`] if the SVD doesn't converge
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let a = Matrix::new(1, 2, vec![1.0, 1.0]);
+    /// let basis = a.null_space(1e-10).unwrap();
+    /// 
+    /// assert_eq!(basis.len(), 1);
+    /// assert!((basis[0][0] + basis[0][1]).abs() < 1e-12);
+    /// ```
+    pub fn null_space(&self, tolerance: f64) -> Result<Vec<Vector<f64>>, MatrixError> {
+        return Ok(self.svd()?.null_space(tolerance));
+    }
+}
+
+impl SVD {
+    /// The matrix `U`, whose columns are the left singular vectors
+    /// 
+    /// Only the first `min(m, n)` columns are orthonormal
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let svd = Matrix::new(3, 2, vec![1.0, 0.0, 0.0, 1.0, 0.0, 0.0]).svd().unwrap();
+    /// 
+    /// assert_eq!((svd.u().rows(), svd.u().cols()), (3, 2));
+    /// 
+    /// // A wide matrix has only two orthonormal columns in U
+    /// let svd = Matrix::new(2, 3, vec![1.0, 0.0, 1.0, 0.0, 1.0, 0.0]).svd().unwrap();
+    /// let u = svd.u().submatrix(0..2, 0..2);
+    /// 
+    /// assert_eq!((svd.u().rows(), svd.u().cols()), (2, 3));
+    /// assert!((u.transpose() * u - Matrix::identity(2)).content().iter().all(|x| x.abs() < 1e-12));
+    /// ```
+    pub fn u(&self) -> &Matrix<f64> {
+        return &self.u;
+    }
+    /// The singular values in descending order
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let svd = Matrix::new(2, 2, vec![0.0, 2.0, 5.0, 0.0]).svd().unwrap();
+    /// 
+    /// assert_eq!(svd.singular_values(), &Vector::new(vec![5.0, 2.0], true));
+    /// ```
+    pub fn singular_values(&self) -> &Vector<f64> {
+        return &self.singular_values;
+    }
+    /// The diagonal matrix `Σ` of singular values
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let svd = Matrix::new(2, 2, vec![0.0, 2.0, 5.0, 0.0]).svd().unwrap();
+    /// 
+    /// assert_eq!(svd.sigma(), Matrix::new(2, 2, vec![5.0, 0.0, 0.0, 2.0]));
+    /// ```
+    pub fn sigma(&self) -> Matrix<f64> {
+        let size = self.singular_values.len();
+        let mut result = Matrix::zeros(size, size);
+        for x in 0..size {
+            result[(x, x)] = self.singular_values[x];
+        }
+        return result;
+    }
+    /// The orthogonal matrix `V`, whose columns are the right singular vectors
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let svd = Matrix::new(2, 3, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]).svd().unwrap();
+    /// 
+    /// assert_eq!((svd.v().rows(), svd.v().cols()), (3, 3));
+    /// ```
+    pub fn v(&self) -> &Matrix<f64> {
+        return &self.v;
+    }
+    /// The number of singular values larger than `tolerance`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let svd = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 1e-12]).svd().unwrap();
+    /// 
+    /// assert_eq!(svd.rank(1e-10), 1);
+    /// assert_eq!(svd.rank(0.0), 2);
+    /// ```
+    pub fn rank(&self, tolerance: f64) -> usize {
+        return self.singular_values.iter().filter(|x| **x > tolerance).count();
+    }
+    /// The 2-norm condition number, the ratio of the largest and smallest singular values
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let svd = Matrix::new(2, 2, vec![4.0, 0.0, 0.0, 0.5]).svd().unwrap();
+    /// 
+    /// assert_eq!(svd.condition_number(), 8.0);
+    /// ```
+    pub fn condition_number(&self) -> f64 {
+        let count = self.rows.min(self.singular_values.len());
+        if count == 0 {
+            return 0.0;
+        }
+        let smallest = self.singular_values[count - 1];
+        if smallest == 0.0 {
+            return f64::INFINITY;
+        }
+        return self.singular_values[0] / smallest;
+    }
+    /// An orthonormal basis of the null space, from the singular values no larger than `tolerance`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let svd = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 0.0]).svd().unwrap();
+    /// 
+    /// assert_eq!(svd.null_space(1e-10)[0][0], 0.0);
+    /// ```
+    pub fn null_space(&self, tolerance: f64) -> Vec<Vector<f64>> {
+        let mut result = vec![];
+        for (col, value) in self.singular_values.iter().enumerate() {
+            if *value <= tolerance {
+                result.push(self.v.col(col));
+            }
+        }
+        return result;
+    }
+    /// The Moore–Penrose pseudoinverse, treating singular values no larger than `tolerance` as zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let svd = Matrix::new(2, 2, vec![2.0, 0.0, 0.0, 1e-20]).svd().unwrap();
+    /// 
+    /// assert_eq!(svd.pseudo_inverse(1e-10), Matrix::new(2, 2, vec![0.5, 0.0, 0.0, 0.0]));
+    /// ```
+    pub fn pseudo_inverse(&self, tolerance: f64) -> Matrix<f64> {
+        let (rows, cols) = (self.v.rows(), self.u.rows());
+        let mut result = Matrix::zeros(rows, cols);
+        for (k, value) in self.singular_values.iter().enumerate() {
+            if *value <= tolerance {
+                continue;
+            }
+            for row in 0..rows {
+                let factor = self.v[(row, k)] / value;
+                for col in 0..cols {
+                    result[(row, col)] += factor * self.u[(col, k)];
+                }
+            }
+        }
+        return result;
+    }
+
+    fn default_tolerance(&self) -> f64 {
+        let largest = self.singular_values.first().copied().unwrap_or(0.0);
+        return largest * f64::EPSILON * self.rows.max(self.v.rows()) as f64;
+    }
+}
+
+// Golub–Kahan–Reinsch: Householder bidiagonalization, then implicit Wilkinson-shifted QR steps on the bidiagonal matrix.
+// `a` needs at least as many rows as columns. U has as many columns as `a`, and the singular values aren't sorted yet
+fn golub_kahan(a: &Matrix<f64>) -> Result<SVD, MatrixError> {
+    let (rows, cols) = (a.rows(), a.cols());
+    let mut work = a.clone();
+    let (mut left, mut right) = (Vec::with_capacity(cols), Vec::with_capacity(cols));
+    // The bidiagonal matrix, where superdiagonal[k] is the entry above diagonal[k] and superdiagonal[0] is unused
+    let (mut diagonal, mut superdiagonal) = (vec![0.0; cols], vec![0.0; cols]);
+    for k in 0..cols {
+        let column: Vec<f64> = (k..rows).map(|row| work[(row, k)]).collect();
+        let reflection = householder(&column);
+        if let Some(w) = &reflection {
+            reflect_rows(&mut work, w, k, k..cols);
+        }
+        left.push(reflection);
+        diagonal[k] = work[(k, k)];
+        if k + 1 < cols {
+            let row: Vec<f64> = (k + 1..cols).map(|col| work[(k, col)]).collect();
+            let reflection = householder(&row);
+            if let Some(w) = &reflection {
+                reflect_cols(&mut work, w, k + 1, k..rows);
+            }
+            right.push(reflection);
+            superdiagonal[k + 1] = work[(k, k + 1)];
+        }
+    }
+    // Multiply the reflections together, last first, so each one only touches the rows it acts on
+    let mut u = Matrix::from_fn(rows, cols, |row, col| if row == col { 1.0 } else { 0.0 });
+    for (k, reflection) in left.iter().enumerate().rev() {
+        if let Some(w) = reflection {
+            reflect_rows(&mut u, w, k, k..cols);
+        }
+    }
+    let mut v = Matrix::identity(cols);
+    for (k, reflection) in right.iter().enumerate().rev() {
+        if let Some(w) = reflection {
+            reflect_rows(&mut v, w, k + 1, k + 1..cols);
+        }
+    }
+
+    let norm = (0..cols).map(|k| diagonal[k].abs() + superdiagonal[k].abs()).fold(0.0, f64::max);
+    // The singular values still being iterated on are low..high
+    let mut high = cols;
+    let mut iterations = 0;
+    while high > 0 {
+        for k in 1..high {
+            if superdiagonal[k].abs() <= f64::EPSILON * (diagonal[k - 1].abs() + diagonal[k].abs()) {
+                superdiagonal[k] = 0.0;
+            }
+        }
+        let mut low = high - 1;
+        while low > 0 && superdiagonal[low] != 0.0 {
+            low -= 1;
+        }
+        if low == high - 1 {
+            high -= 1;
+            iterations = 0;
+            continue;
+        }
+        if iterations == MAX_ITERATIONS {
+            return Err(MatrixError::NoConvergence);
+        }
+        iterations += 1;
+        let last = high - 1;
+        // A zero on the diagonal splits the block once the superdiagonal entry next to it is rotated away
+        if let Some(k) = (low..last).find(|k| diagonal[*k].abs() <= f64::EPSILON * norm) {
+            diagonal[k] = 0.0;
+            let mut bulge = superdiagonal[k + 1];
+            superdiagonal[k + 1] = 0.0;
+            for j in k + 1..high {
+                let (c, s, r) = rotation(diagonal[j], bulge);
+                diagonal[j] = r;
+                rotate_cols(&mut u, j, k, c, s);
+                if j < last {
+                    bulge = -s * superdiagonal[j + 1];
+                    superdiagonal[j + 1] *= c;
+                }
+            }
+            continue;
+        }
+        if diagonal[last].abs() <= f64::EPSILON * norm {
+            diagonal[last] = 0.0;
+            let mut bulge = superdiagonal[last];
+            superdiagonal[last] = 0.0;
+            for j in (low..last).rev() {
+                let (c, s, r) = rotation(diagonal[j], bulge);
+                diagonal[j] = r;
+                rotate_cols(&mut v, j, last, c, s);
+                if j > low {
+                    bulge = -s * superdiagonal[j];
+                    superdiagonal[j] *= c;
+                }
+            }
+            continue;
+        }
+        // The shift is the eigenvalue of the trailing 2x2 block of BᵀB closest to its last diagonal entry
+        let above = if last - 1 > low { superdiagonal[last - 1] } else { 0.0 };
+        let t11 = diagonal[last - 1] * diagonal[last - 1] + above * above;
+        let t12 = diagonal[last - 1] * superdiagonal[last];
+        let t22 = diagonal[last] * diagonal[last] + superdiagonal[last] * superdiagonal[last];
+        let half_difference = 0.5 * (t11 - t22);
+        let shift = match t12 == 0.0 {
+            true => t22,
+            false => t22 - t12 * t12 / (half_difference + half_difference.hypot(t12).copysign(half_difference)),
+        };
+        let mut y = diagonal[low] * diagonal[low] - shift;
+        let mut z = diagonal[low] * superdiagonal[low + 1];
+        for k in low..last {
+            // Rotate columns k and k + 1, which pushes a bulge below the diagonal
+            let (c, s, r) = rotation(y, z);
+            if k > low {
+                superdiagonal[k] = r;
+            }
+            let (d, e) = (diagonal[k], superdiagonal[k + 1]);
+            diagonal[k] = c * d + s * e;
+            superdiagonal[k + 1] = c * e - s * d;
+            let bulge = s * diagonal[k + 1];
+            diagonal[k + 1] *= c;
+            rotate_cols(&mut v, k, k + 1, c, s);
+            // Rotate rows k and k + 1 to remove it, which pushes it to the right of the superdiagonal
+            let (c, s, r) = rotation(diagonal[k], bulge);
+            diagonal[k] = r;
+            let (e, d) = (superdiagonal[k + 1], diagonal[k + 1]);
+            superdiagonal[k + 1] = c * e + s * d;
+            diagonal[k + 1] = c * d - s * e;
+            rotate_cols(&mut u, k, k + 1, c, s);
+            if k + 1 < last {
+                y = superdiagonal[k + 1];
+                z = s * superdiagonal[k + 2];
+                superdiagonal[k + 2] *= c;
+            }
+        }
+    }
+    for (k, value) in diagonal.iter_mut().enumerate() {
+        if *value < 0.0 {
+            *value = -*value;
+            for row in 0..cols {
+                v[(row, k)] = -v[(row, k)];
+            }
+        }
+    }
+    return Ok(SVD {
+        u,
+        singular_values: Vector::new(diagonal, true),
+        v,
+        rows
+    });
+}
+
+// A Householder reflection I - βwwᵀ that takes x to a multiple of the first unit vector, as (w, β), or None if x is zero
+fn householder(x: &[f64]) -> Option<(Vec<f64>, f64)> {
+    let norm = x.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return None;
+    }
+    let mut w = x.to_vec();
+    w[0] += norm.copysign(w[0]);
+    let beta = 2.0 / w.iter().map(|x| x * x).sum::<f64>();
+    return Some((w, beta));
+}
+
+// Multiply the rows start..start + w.len() by I - βwwᵀ from the left, only in the columns `cols`
+fn reflect_rows(a: &mut Matrix<f64>, (w, beta): &(Vec<f64>, f64), start: usize, cols: std::ops::Range<usize>) {
+    for col in cols {
+        let dot: f64 = w.iter().enumerate().map(|(i, x)| x * a[(start + i, col)]).sum();
+        for (i, x) in w.iter().enumerate() {
+            a[(start + i, col)] -= beta * dot * x;
+        }
+    }
+}
+
+// Multiply the columns start..start + w.len() by I - βwwᵀ from the right, only in the rows `rows`
+fn reflect_cols(a: &mut Matrix<f64>, (w, beta): &(Vec<f64>, f64), start: usize, rows: std::ops::Range<usize>) {
+    for row in rows {
+        let dot: f64 = w.iter().enumerate().map(|(i, x)| x * a[(row, start + i)]).sum();
+        for (i, x) in w.iter().enumerate() {
+            a[(row, start + i)] -= beta * dot * x;
+        }
+    }
+}
+
+// The cosine and sine with c * y + s * z = r and c * z - s * y = 0
+fn rotation(y: f64, z: f64) -> (f64, f64, f64) {
+    let r = y.hypot(z);
+    if r == 0.0 {
+        return (1.0, 0.0, 0.0);
+    }
+    return (y / r, z / r, r);
+}
+
+// Replace columns i and j with c * i + s * j and c * j - s * i
+fn rotate_cols(a: &mut Matrix<f64>, i: usize, j: usize, c: f64, s: f64) {
+    for row in 0..a.rows() {
+        let (x, y) = (a[(row, i)], a[(row, j)]);
+        a[(row, i)] = c * x + s * y;
+        a[(row, j)] = c * y - s * x;
+    }
+}
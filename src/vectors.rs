@@ -5,9 +5,10 @@ Math Vectors;
 # Examples
 ```rust
 use lemonmath::vectors::Vector;
+use lemonmath::matrices::Matrix;
 
 // Create Vector from a list of numbers
-let x = Vector::new(vec![1, 2, 3], true);
+let x = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], true);
 
 assert_eq!(x.content, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
 
@@ -27,21 +28,29 @@ vector1.transpose();
 assert_eq!(format!("{}", vector1), format!("{}", vector2));
 
 // Display Trait
-let x = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], true);
+let x = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], false);
 
-assert_eq!(format!("{}", x), "[ 1.0 2.0 3.0 4.0 5.0 ]");
+assert_eq!(format!("{}", x), "[ 1 2 3 4 5 ]");
 
-// Dot Product
-let vector1 = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], true);
-let vector2 = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], false);
+// Dot Product (row times column)
+let vector1 = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], false);
+let vector2 = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], true);
 
 assert_eq!(vector1 * vector2, 55.0);
+
+// Outer Product (column times row)
+let column = Vector::new(vec![1, 2], true);
+let row = Vector::new(vec![3, 4], false);
+
+assert_eq!(column.outer(&row), Some(Matrix::new(2, 2, vec![3, 4, 6, 8])));
 ```
 */
 
-use std::{fmt::Display, ops::{Mul, AddAssign, Add, Sub, Div}};
+use std::{fmt::Display, error::Error, iter::FromIterator, slice::{self, SliceIndex}, vec, ops::{Mul, AddAssign, Add, Sub, Div, Index, IndexMut, Deref, DerefMut}};
 
 use crate::helper::VecToFraction;
+use crate::matrices::Matrix;
+use crate::layout::Layout;
 
 #[test]
 pub fn vector_test() {
@@ -75,10 +84,9 @@ impl<T> Vector<T> {
     /// ```
     pub fn new(content: Vec<T>, column_or_row: bool) -> Self {
         return Vector { 
-            content: content, 
-            column_or_row 
+            content,
+            column_or_row
         };
-        
     }
     /// Push new values into the vector
     /// 
@@ -111,50 +119,429 @@ impl<T> Vector<T> {
     pub fn transpose(&mut self) {
         self.column_or_row = !self.column_or_row;
     }
+    /// Check if the vector is a column vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1.0, 2.0, 3.0], true);
+    /// let y = Vector::new(vec![1.0, 2.0, 3.0], false);
+    /// 
+    /// assert!(x.is_column());
+    /// assert!(!y.is_column());
+    /// ```
+    pub fn is_column(&self) -> bool {
+        return self.column_or_row;
+    }
+    /// The number of elements in the vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1.0, 2.0, 3.0], true);
+    /// 
+    /// assert_eq!(x.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        return self.content.len();
+    }
+    /// Check if the vector has no elements
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x: Vector<f64> = Vector::new(vec![], true);
+    /// 
+    /// assert!(x.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        return self.content.is_empty();
+    }
+    /// Apply a function to every element, keeping the orientation
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3], false);
+    /// 
+    /// assert_eq!(x.map(|x| x * 10), Vector::new(vec![10, 20, 30], false));
+    /// ```
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Vector<U> {
+        return Vector::new(self.content.iter().map(f).collect(), self.column_or_row);
+    }
+    /// Combine two vectors element by element, keeping the orientation of `self`
+    /// 
+    /// # Panics
+    /// Panics if the vectors have different lengths
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3], true);
+    /// let y = Vector::new(vec![4, 5, 6], true);
+    /// 
+    /// assert_eq!(x.zip_with(&y, |a, b| a * b), Vector::new(vec![4, 10, 18], true));
+    /// ```
+    pub fn zip_with<U, V, F: FnMut(&T, &U) -> V>(&self, other: &Vector<U>, mut f: F) -> Vector<V> {
+        if self.content.len() != other.content.len() {
+            panic!("Vectors must have the same length");
+        }
+        let content = self.content.iter().zip(other.content.iter()).map(|(a, b)| f(a, b)).collect();
+        return Vector::new(content, self.column_or_row);
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> Index<I> for Vector<T> {
+    type Output = I::Output;
+
+    /// Index or slice the vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3, 4], true);
+    /// 
+    /// assert_eq!(x[1], 2);
+    /// assert_eq!(x[1..3], [2, 3]);
+    /// ```
+    fn index(&self, index: I) -> &Self::Output {
+        return &self.content[index];
+    }
+}
+
+impl<T, I: SliceIndex<[T]>> IndexMut<I> for Vector<T> {
+    /// Mutably index or slice the vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let mut x = Vector::new(vec![1, 2, 3, 4], true);
+    /// 
+    /// x[0] = 5;
+    /// x[2..].copy_from_slice(&[7, 8]);
+    /// 
+    /// assert_eq!(x, Vector::new(vec![5, 2, 7, 8], true));
+    /// ```
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        return &mut self.content[index];
+    }
+}
+
+impl<T> Deref for Vector<T> {
+    type Target = [T];
+
+    /// Borrow the vector as a slice
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![3, 1, 2], true);
+    /// 
+    /// assert_eq!(x.iter().max(), Some(&3));
+    /// assert!(x.contains(&2));
+    /// ```
+    fn deref(&self) -> &Self::Target {
+        return &self.content;
+    }
+}
+
+impl<T> DerefMut for Vector<T> {
+    /// Mutably borrow the vector as a slice
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let mut x = Vector::new(vec![3, 1, 2], true);
+    /// 
+    /// x.sort();
+    /// 
+    /// assert_eq!(x, Vector::new(vec![1, 2, 3], true));
+    /// ```
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        return &mut self.content;
+    }
+}
+
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    /// Iterate over the elements of the vector by value
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3], true);
+    /// 
+    /// assert_eq!(x.into_iter().sum::<i32>(), 6);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        return self.content.into_iter();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    /// Iterate over references to the elements of the vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3], true);
+    /// let mut total = 0;
+    /// 
+    /// for value in &x {
+    ///     total += value;
+    /// }
+    /// 
+    /// assert_eq!(total, 6);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        return self.content.iter();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Vector<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    /// Iterate over mutable references to the elements of the vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let mut x = Vector::new(vec![1, 2, 3], true);
+    /// 
+    /// for value in &mut x {
+    ///     *value *= 2;
+    /// }
+    /// 
+    /// assert_eq!(x, Vector::new(vec![2, 4, 6], true));
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        return self.content.iter_mut();
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    /// Collect an iterator into a column vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x: Vector<i32> = (1..=3).collect();
+    /// 
+    /// assert_eq!(x, Vector::new(vec![1, 2, 3], true));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        return Vector::new(iter.into_iter().collect(), true);
+    }
+}
+
+impl<T> Extend<T> for Vector<T> {
+    /// Push every element of an iterator into the vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let mut x = Vector::new(vec![1, 2], false);
+    /// 
+    /// x.extend(vec![3, 4]);
+    /// 
+    /// assert_eq!(x, Vector::new(vec![1, 2, 3, 4], false));
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.content.extend(iter);
+    }
+}
+
+impl<T: AddAssign + Default + Mul + Mul<Output = T> + Copy> Vector<T> {
+    /// Inner product of a row vector and a column vector
+    /// 
+    /// Returns `None` unless `self` is a row, `other` is a column and both have the same length
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let row = Vector::new(vec![1, 2, 3], false);
+    /// let column = Vector::new(vec![4, 5, 6], true);
+    /// 
+    /// assert_eq!(row.inner(&column), Some(32));
+    /// assert_eq!(column.inner(&row), None);
+    /// ```
+    pub fn inner(&self, other: &Self) -> Option<T> {
+        if self.column_or_row || !other.column_or_row || self.content.len() != other.content.len() {
+            return None;
+        }
+        let mut result = T::default();
+        for x in 0..self.content.len() {
+            result += self.content[x] * other.content[x];
+        }
+        return Some(result);
+    }
+    /// Outer product of a column vector and a row vector
+    /// 
+    /// The result has a row for every element of `self` and a column for every element of `other`.
+    /// Returns `None` unless `self` is a column and `other` is a row
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let column = Vector::new(vec![1, 2, 3], true);
+    /// let row = Vector::new(vec![4, 5], false);
+    /// 
+    /// assert_eq!(column.outer(&row), Some(Matrix::new(3, 2, vec![4, 5, 8, 10, 12, 15])));
+    /// assert_eq!(row.outer(&column), None);
+    /// ```
+    pub fn outer(&self, other: &Self) -> Option<Matrix<T>> {
+        if !self.column_or_row || other.column_or_row {
+            return None;
+        }
+        let mut content = Vec::with_capacity(self.content.len() * other.content.len());
+        for x in &self.content {
+            for y in &other.content {
+                content.push(*x * *y);
+            }
+        }
+        return Some(Matrix::new(self.content.len(), other.content.len(), content));
+    }
+    /// Dot product of two vectors of the same length, ignoring their orientation
+    /// 
+    /// # Panics
+    /// Panics if the vectors have different lengths
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3], true);
+    /// let y = Vector::new(vec![4, 5, 6], true);
+    /// 
+    /// assert_eq!(x.dot(&y), 32);
+    /// ```
+    pub fn dot(&self, other: &Self) -> T {
+        if self.content.len() != other.content.len() {
+            panic!("Vectors must have the same length");
+        }
+        let mut result = T::default();
+        for x in 0..self.content.len() {
+            result += self.content[x] * other.content[x];
+        }
+        return result;
+    }
+    /// Multiply every element of the vector by a scalar
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Vector::new(vec![1, 2, 3], true);
+    /// 
+    /// assert_eq!(x.scale(2), Vector::new(vec![2, 4, 6], true));
+    /// ```
+    pub fn scale(&self, factor: T) -> Self {
+        let mut result = Vector::new(Vec::with_capacity(self.content.len()), self.column_or_row);
+        for x in &self.content {
+            result.content.push(*x * factor);
+        }
+        return result;
+    }
+}
+
+impl<T: AddAssign + Default + PartialEq + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy> Vector<T> {
+    /// Check if every element of the vector is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// assert!(Vector::new(vec![0.0, 0.0], true).is_zero());
+    /// assert!(!Vector::new(vec![0.0, 1.0], true).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        return self.content.iter().all(|x| *x == T::default());
+    }
+    /// Projection of the vector onto another vector
+    /// 
+    /// The result keeps the orientation of `self`. Returns `None` if `other` is the zero vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// use lemonmath::fraction::Fraction;
+    /// 
+    /// let x = Vector::new(vec![Fraction::new(1, 1), Fraction::new(2, 1)], true);
+    /// let y = Vector::new(vec![Fraction::new(1, 1), Fraction::new(1, 1)], true);
+    /// 
+    /// assert_eq!(x.project_onto(&y), Some(Vector::new(vec![Fraction::new(3, 2), Fraction::new(3, 2)], true)));
+    /// ```
+    pub fn project_onto(&self, other: &Self) -> Option<Self> {
+        let denominator = other.dot(other);
+        if denominator == T::default() {
+            return None;
+        }
+        let mut result = other.scale(self.dot(other) / denominator);
+        result.column_or_row = self.column_or_row;
+        return Some(result);
+    }
+    /// Component of the vector orthogonal to another vector
+    /// 
+    /// This is the vector minus its projection onto `other`. Returns `None` if `other` is the zero vector
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::vectors::Vector;
+    /// use lemonmath::fraction::Fraction;
+    /// 
+    /// let x = Vector::new(vec![Fraction::new(1, 1), Fraction::new(2, 1)], true);
+    /// let y = Vector::new(vec![Fraction::new(1, 1), Fraction::new(1, 1)], true);
+    /// 
+    /// assert_eq!(x.reject_from(&y), Some(Vector::new(vec![Fraction::new(-1, 2), Fraction::new(1, 2)], true)));
+    /// ```
+    pub fn reject_from(&self, other: &Self) -> Option<Self> {
+        let projection = self.project_onto(other)?;
+        return Some(self.clone() - projection);
+    }
 }
 
 impl<T: Display> Display for Vector<T> {
     /// Display the vector
     /// 
+    /// Column vectors are right aligned, use [`Layout`] for other alignments or ASCII brackets
+    /// 
     /// # Examples
     /// ```
     /// use lemonmath::vectors::Vector;
     /// 
-    /// let x = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], true);
+    /// let x = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], false);
     /// 
-    /// assert_eq!(format!("{}", x), "[ 1.0 2.0 3.0 4.0 5.0 ]");
+    /// assert_eq!(format!("{}", x), "[ 1 2 3 4 5 ]");
+    /// 
+    /// let y = Vector::new(vec![1, 20, 300], true);
+    /// 
+    /// assert_eq!(format!("{}", y), "⎡   1 ⎤\n⎢  20 ⎥\n⎣ 300 ⎦");
     /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut final_str = String::new();
-        if self.column_or_row == true {
-            let mut max_length = 1;
-            for x in &self.content {
-                if format!("{}", x).len() > max_length {
-                    max_length = format!("{}", x).len();
-                }
-            }
-            for x in (&self.content).into_iter().enumerate() {
-                let mut padding = String::new();
-                for _ in 0..(max_length - format!("{}", x.1).len()) {
-                    padding.push(' ');
-                }
-                if x.0 == 0 {
-                    final_str.push_str(&format!("⎡ {}{} ⎤\n", x.1, padding));
-                } else if x.0 + 1 == self.content.len() {
-                    final_str.push_str(&format!("⎣ {}{} ⎦", x.1, padding));
-                } else {
-                    final_str.push_str(&format!("⎢ {}{} ⎥\n", x.1, padding));
-                }
-            }
-        } else {
-            final_str.push('[');
-            final_str.push(' ');
-            for x in &self.content {
-                final_str.push_str(format!("{} ", x).as_str());
-            }
-            final_str.push(']');
-        }
-        return f.write_str(final_str.as_str());
+        return f.write_str(&Layout::default().render(self));
     }
 }
 
@@ -207,30 +594,30 @@ impl<T: Sub + Sub<Output = T> + Copy> Sub for Vector<T> {
 impl<T: AddAssign + Default + Mul + Mul<Output = T> + Copy + Display> Mul for Vector<T>{
     type Output = T;
 
-    /// Dot Product
+    /// Dot Product of a row vector and a column vector
+    /// 
+    /// Use [`Vector::inner`] to avoid panicking, or [`Vector::outer`] to multiply a column by a row
+    /// 
+    /// # Panics
+    /// Panics if the vectors have different lengths, or if `self` isn't a row and `other` isn't a column
     /// 
     /// # Examples
     /// ```
     /// use lemonmath::vectors::Vector;
     /// 
-    /// let vector1 = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], true);
-    /// let vector2 = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], false);
+    /// let vector1 = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], false);
+    /// let vector2 = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0], true);
     /// 
     /// assert_eq!(vector1 * vector2, 55.0);
     /// ```
     fn mul(self, other: Self) -> Self::Output {
-        let mut result = T::default();
         if self.content.len() != other.content.len() {
             panic!("Vectors must have the same length");
         }
-        if self.column_or_row != other.column_or_row {
-            for x in 0..self.content.len() {
-                result += self.content[x] * other.content[x];
-            }
-        } else {
-            panic!("Can't multiply two vectors with the same orientation");
+        match self.inner(&other) {
+            Some(result) => return result,
+            None => panic!("Can only multiply a row vector by a column vector, use Vector::outer for a column times a row"),
         }
-        return result;
     }
 }
 
@@ -255,4 +642,120 @@ impl<T: From<u8> + Mul + Div + Div<Output = T> + Mul<Output = T> + Copy> Div for
         }
         return result;
     }
+}
+
+/// Which variant of the Gram-Schmidt process to use
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GramSchmidt {
+    /// Subtract the projections of the original vector onto every previous basis vector
+    Classical,
+    /// Subtract each projection from the running remainder, which is more stable for floats
+    Modified,
+}
+
+/// The error returned when the Gram-Schmidt process meets a linearly dependent vector
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LinearlyDependent {
+    /// The index of the first input vector that depends on the ones before it
+    pub index: usize,
+}
+
+impl Display for LinearlyDependent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "vector {} is linearly dependent on the vectors before it", self.index)
+    }
+}
+
+impl Error for LinearlyDependent {}
+
+/// Orthogonalize a list of vectors with the Gram-Schmidt process
+/// 
+/// The result is orthogonal but not normalized, so with `Fraction` vectors it is exact and needs no square roots.
+/// Use [`orthonormalize`] for normalized `f64` bases
+/// 
+/// # Errors
+/// Returns [`LinearlyDependent`] if a vector is a linear combination of the ones before it
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::vectors::{gram_schmidt, GramSchmidt, Vector};
+/// use lemonmath::fraction::Fraction;
+/// use lemonmath::helper::VecToFraction;
+/// 
+/// let basis = gram_schmidt(&[
+///     Vector::new(vec![1, 1, 0].to_fraction(), true),
+///     Vector::new(vec![1, 0, 1].to_fraction(), true),
+/// ], GramSchmidt::Modified).unwrap();
+/// 
+/// assert_eq!(basis[1], Vector::new(vec![Fraction::new(1, 2), Fraction::new(-1, 2), Fraction::new(1, 1)], true));
+/// 
+/// let dependent = gram_schmidt(&[
+///     Vector::new(vec![1, 2].to_fraction(), true),
+///     Vector::new(vec![2, 4].to_fraction(), true),
+/// ], GramSchmidt::Classical);
+/// 
+/// assert_eq!(dependent.unwrap_err().index, 1);
+/// ```
+pub fn gram_schmidt<T>(vectors: &[Vector<T>], method: GramSchmidt) -> Result<Vec<Vector<T>>, LinearlyDependent>
+where
+    T: AddAssign + Default + PartialEq + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Copy,
+{
+    let mut basis: Vec<Vector<T>> = Vec::with_capacity(vectors.len());
+    for (index, vector) in vectors.iter().enumerate() {
+        let mut remainder = vector.clone();
+        for previous in &basis {
+            let projection = match method {
+                GramSchmidt::Classical => vector.project_onto(previous),
+                GramSchmidt::Modified => remainder.project_onto(previous),
+            };
+            // Every vector in the basis is non-zero, so the projection always exists
+            remainder = remainder - projection.unwrap();
+        }
+        if remainder.is_zero() {
+            return Err(LinearlyDependent { index });
+        }
+        basis.push(remainder);
+    }
+    return Ok(basis);
+}
+
+/// Orthonormalize a list of `f64` vectors with the Gram-Schmidt process
+/// 
+/// A vector counts as linearly dependent when what is left of it after removing the previous
+/// directions is no longer than `tolerance` times its original length
+/// 
+/// # Errors
+/// Returns [`LinearlyDependent`] if a vector is (numerically) a linear combination of the ones before it
+/// 
+/// # Examples
+/// ```
+/// use lemonmath::vectors::{orthonormalize, GramSchmidt, Vector};
+/// 
+/// let basis = orthonormalize(&[
+///     Vector::new(vec![3.0, 4.0], true),
+///     Vector::new(vec![1.0, 0.0], true),
+/// ], GramSchmidt::Modified, 1e-12).unwrap();
+/// 
+/// assert!((basis[0].content[0] - 0.6).abs() < 1e-12);
+/// assert!((basis[1].content[1] + 0.6).abs() < 1e-12);
+/// assert!(basis[0].dot(&basis[1]).abs() < 1e-12);
+/// ```
+pub fn orthonormalize(vectors: &[Vector<f64>], method: GramSchmidt, tolerance: f64) -> Result<Vec<Vector<f64>>, LinearlyDependent> {
+    let mut basis: Vec<Vector<f64>> = Vec::with_capacity(vectors.len());
+    for (index, vector) in vectors.iter().enumerate() {
+        let mut remainder = vector.clone();
+        for previous in &basis {
+            let coefficient = match method {
+                GramSchmidt::Classical => vector.dot(previous),
+                GramSchmidt::Modified => remainder.dot(previous),
+            };
+            remainder = remainder - previous.scale(coefficient);
+        }
+        let norm = remainder.dot(&remainder).sqrt();
+        if norm <= tolerance * vector.dot(vector).sqrt() || norm == 0.0 {
+            return Err(LinearlyDependent { index });
+        }
+        basis.push(remainder.scale(1.0 / norm));
+    }
+    return Ok(basis);
 }
\ No newline at end of file
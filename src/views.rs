@@ -0,0 +1,950 @@
+/*!
+# Views
+Borrowed views of matrices, so rows, columns and blocks can be used without copying
+
+# Examples
+```rust
+use lemonmath::matrices::Matrix;
+use lemonmath::vectors::Vector;
+
+let mut x = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+// Views borrow the matrix instead of copying it
+let block = x.block_view(1..3, 1..3);
+
+assert_eq!(block.to_matrix(), Matrix::new(2, 2, vec![5, 6, 8, 9]));
+assert_eq!(block.transpose().to_matrix(), Matrix::new(2, 2, vec![5, 8, 6, 9]));
+
+// Every other row and column
+assert_eq!(x.view().step(2, 2).to_matrix(), Matrix::new(2, 2, vec![1, 3, 7, 9]));
+
+// A row view works like a row vector
+let column = Vector::new(vec![1, 1, 1], true);
+
+assert_eq!(x.row_view(1).inner(&column), Some(15));
+assert_eq!(Vector::from(x.row_view(1)), x.row(1));
+
+// Arithmetic between views gives a new matrix
+assert_eq!(x.row_view(0) + x.row_view(2), Matrix::new(1, 3, vec![8, 10, 12]));
+
+// Mutable views write through to the matrix
+x.block_view_mut(0..2, 0..2).fill(0);
+
+assert_eq!(x, Matrix::new(3, 3, vec![0, 0, 3, 0, 0, 6, 7, 8, 9]));
+```
+*/
+
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Range, Sub, SubAssign};
+
+use crate::layout::{Layout, Render};
+use crate::matrices::{Matrix, MatrixError};
+use crate::vectors::Vector;
+
+#[test]
+fn views_test() {
+    use std::panic::catch_unwind;
+
+    let mut x = Matrix::new(4, 5, (0..20).collect());
+
+    // Steps multiply the strides of the view they are taken from
+    assert_eq!(x.view().step(2, 2).to_matrix(), Matrix::new(2, 3, vec![0, 2, 4, 10, 12, 14]));
+    assert_eq!(x.block_view(1..4, 1..5).step(2, 3).to_matrix(), Matrix::new(2, 2, vec![6, 9, 16, 19]));
+    assert_eq!(x.view().step(3, 2).transpose().to_matrix(), Matrix::new(3, 2, vec![0, 15, 2, 17, 4, 19]));
+    assert_eq!(x.view().step(2, 2).step(1, 2).to_matrix(), Matrix::new(2, 2, vec![0, 4, 10, 14]));
+    assert_eq!(x.view().step(2, 2).submatrix(1..2, 1..3).to_matrix(), Matrix::new(1, 2, vec![12, 14]));
+
+    // Nested mutable blocks are offset from the block they are taken from
+    let mut block = x.block_view_mut(1..4, 1..5);
+    let mut inner = block.submatrix_mut(1..3, 2..4);
+    inner.submatrix_mut(1..2, 0..2).fill(-1);
+    inner[(0, 1)] = -2;
+    assert_eq!(x.submatrix(1..4, 1..5), Matrix::new(3, 4, vec![6, 7, 8, 9, 11, 12, 13, -2, 16, 17, -1, -1]));
+    let mut block = x.block_view_mut(0..4, 4..5);
+    block.submatrix_mut(2..4, 0..1).fill(0);
+    assert_eq!(x.col(4), Vector::new(vec![4, 9, 0, 0], true));
+
+    // Empty blocks at the edge are allowed
+    assert_eq!(x.block_view(4..4, 5..5).rows(), 0);
+
+    // Ranges are checked against the view, not the whole matrix
+    let (start, end) = (2, 1);
+    assert!(catch_unwind(|| x.block_view(0..5, 0..1)).is_err());
+    assert!(catch_unwind(|| x.block_view(0..1, 3..6)).is_err());
+    assert!(catch_unwind(|| x.block_view(start..end, 0..1)).is_err());
+    assert!(catch_unwind(|| x.block_view(0..2, 0..2).submatrix(0..3, 0..1)).is_err());
+    assert!(catch_unwind(|| x.view().step(2, 2).submatrix(0..1, 0..4)).is_err());
+    assert!(catch_unwind(|| {
+        let mut x = Matrix::new(3, 3, vec![0; 9]);
+        x.block_view_mut(1..3, 1..3).submatrix_mut(1..3, 0..1).fill(1);
+    }).is_err());
+}
+
+/// A borrowed, possibly strided, rectangular part of a matrix
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+/// A mutably borrowed, possibly strided, rectangular part of a matrix
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    data: &'a mut [T],
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl<T> Matrix<T> {
+    /// View the whole matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view()[(1, 0)], 3);
+    /// ```
+    pub fn view(&self) -> MatrixView<'_, T> {
+        return MatrixView {
+            data: self.content(),
+            offset: 0,
+            rows: self.rows(),
+            cols: self.cols(),
+            row_stride: self.cols(),
+            col_stride: 1,
+        };
+    }
+    /// View the whole matrix mutably
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// x.view_mut()[(1, 0)] = 5;
+    /// 
+    /// assert_eq!(x[(1, 0)], 5);
+    /// ```
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (rows, cols) = (self.rows(), self.cols());
+        return MatrixViewMut {
+            data: self.content_mut(),
+            offset: 0,
+            rows,
+            cols,
+            row_stride: cols,
+            col_stride: 1,
+        };
+    }
+    /// View a row of the matrix as a 1 by n view
+    /// 
+    /// # Panics
+    /// Panics if the row is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.row_view(1).iter().collect::<Vec<_>>(), vec![&3, &4]);
+    /// ```
+    pub fn row_view(&self, row: usize) -> MatrixView<'_, T> {
+        return self.view().row(row);
+    }
+    /// View a column of the matrix as an m by 1 view
+    /// 
+    /// # Panics
+    /// Panics if the column is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.col_view(1).iter().collect::<Vec<_>>(), vec![&2, &4]);
+    /// ```
+    pub fn col_view(&self, col: usize) -> MatrixView<'_, T> {
+        return self.view().col(col);
+    }
+    /// View the block covered by a range of rows and a range of columns
+    /// 
+    /// # Panics
+    /// Panics if a range is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// 
+    /// assert_eq!(x.block_view(0..2, 1..3).to_matrix(), x.submatrix(0..2, 1..3));
+    /// ```
+    pub fn block_view(&self, rows: Range<usize>, cols: Range<usize>) -> MatrixView<'_, T> {
+        return self.view().submatrix(rows, cols);
+    }
+    /// Mutably view the block covered by a range of rows and a range of columns
+    /// 
+    /// # Panics
+    /// Panics if a range is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// x.block_view_mut(0..2, 2..3).fill(0);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 3, vec![1, 2, 0, 4, 5, 0]));
+    /// ```
+    pub fn block_view_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T> {
+        let (offset, rows, cols) = check_block(self.rows(), self.cols(), rows, cols, self.cols(), 1);
+        let row_stride = self.cols();
+        return MatrixViewMut {
+            data: self.content_mut(),
+            offset,
+            rows,
+            cols,
+            row_stride,
+            col_stride: 1,
+        };
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// The number of rows in the view
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.view().rows(), 2);
+    /// ```
+    pub fn rows(&self) -> usize {
+        return self.rows;
+    }
+    /// The number of columns in the view
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.view().cols(), 3);
+    /// ```
+    pub fn cols(&self) -> usize {
+        return self.cols;
+    }
+    /// Get an element of the view, or `None` if the position is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view().transpose().get(0, 1), Some(&3));
+    /// assert_eq!(x.view().get(2, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        return Some(&self.data[self.offset + row * self.row_stride + col * self.col_stride]);
+    }
+    /// View a row of this view
+    /// 
+    /// # Panics
+    /// Panics if the row is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view().row(0).to_matrix(), Matrix::new(1, 2, vec![1, 2]));
+    /// ```
+    pub fn row(&self, row: usize) -> Self {
+        return self.submatrix(row..row + 1, 0..self.cols);
+    }
+    /// View a column of this view
+    /// 
+    /// # Panics
+    /// Panics if the column is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view().col(0).to_matrix(), Matrix::new(2, 1, vec![1, 3]));
+    /// ```
+    pub fn col(&self, col: usize) -> Self {
+        return self.submatrix(0..self.rows, col..col + 1);
+    }
+    /// View the block of this view covered by a range of rows and a range of columns
+    /// 
+    /// # Panics
+    /// Panics if a range is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// let block = x.view().submatrix(1..3, 0..3).submatrix(0..2, 1..2);
+    /// 
+    /// assert_eq!(block.to_matrix(), Matrix::new(2, 1, vec![5, 8]));
+    /// ```
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Self {
+        let (offset, rows, cols) = check_block(self.rows, self.cols, rows, cols, self.row_stride, self.col_stride);
+        return MatrixView {
+            data: self.data,
+            offset: self.offset + offset,
+            rows,
+            cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        };
+    }
+    /// View every `row_step`th row and every `col_step`th column, starting with the first
+    /// 
+    /// # Panics
+    /// Panics if a step is zero
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 4, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    /// 
+    /// assert_eq!(x.view().step(1, 3).to_matrix(), Matrix::new(2, 2, vec![1, 4, 5, 8]));
+    /// ```
+    pub fn step(&self, row_step: usize, col_step: usize) -> Self {
+        if row_step == 0 || col_step == 0 {
+            panic!("Steps must be at least 1");
+        }
+        return MatrixView {
+            data: self.data,
+            offset: self.offset,
+            rows: self.rows.div_ceil(row_step),
+            cols: self.cols.div_ceil(col_step),
+            row_stride: self.row_stride * row_step,
+            col_stride: self.col_stride * col_step,
+        };
+    }
+    /// View the transpose, without moving any elements
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.view().transpose().to_matrix(), x.transpose());
+    /// ```
+    pub fn transpose(&self) -> Self {
+        return MatrixView {
+            data: self.data,
+            offset: self.offset,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        };
+    }
+    /// Iterate over the elements in row order
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view().transpose().iter().copied().collect::<Vec<_>>(), vec![1, 3, 2, 4]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let view = *self;
+        return (0..self.rows).flat_map(move |row| (0..view.cols).map(move |col| &view.data[view.offset + row * view.row_stride + col * view.col_stride]));
+    }
+    /// Iterate over the rows as 1 by n views
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let sums: Vec<i32> = x.view().row_iter().map(|row| row.iter().sum()).collect();
+    /// 
+    /// assert_eq!(sums, vec![3, 7]);
+    /// ```
+    pub fn row_iter(&self) -> impl Iterator<Item = MatrixView<'a, T>> {
+        let view = *self;
+        return (0..self.rows).map(move |row| view.row(row));
+    }
+    /// Iterate over the columns as m by 1 views
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let sums: Vec<i32> = x.view().col_iter().map(|col| col.iter().sum()).collect();
+    /// 
+    /// assert_eq!(sums, vec![4, 6]);
+    /// ```
+    pub fn col_iter(&self) -> impl Iterator<Item = MatrixView<'a, T>> {
+        let view = *self;
+        return (0..self.cols).map(move |col| view.col(col));
+    }
+}
+
+impl<'a, T: Clone> MatrixView<'a, T> {
+    /// Copy the view into a new matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view().to_matrix(), x);
+    /// ```
+    pub fn to_matrix(&self) -> Matrix<T> {
+        return Matrix::new(self.rows, self.cols, self.iter().cloned().collect());
+    }
+    /// Copy a single row view into a row vector, or a single column view into a column vector
+    /// 
+    /// # Panics
+    /// Panics if the view has more than one row and more than one column
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.row_view(0).to_vector(), Vector::new(vec![1, 2], false));
+    /// assert_eq!(x.col_view(0).to_vector(), Vector::new(vec![1, 3], true));
+    /// ```
+    pub fn to_vector(&self) -> Vector<T> {
+        if self.rows != 1 && self.cols != 1 {
+            panic!("Only a single row or column can be turned into a vector, not a {}x{} view", self.rows, self.cols);
+        }
+        return Vector::new(self.iter().cloned().collect(), self.rows != 1);
+    }
+}
+
+impl<'a, T: AddAssign + Default + Mul<Output = T> + Copy> MatrixView<'a, T> {
+    /// Inner product of a single row view and a column vector, the same as [`Vector::inner`] for a row vector
+    /// 
+    /// Returns `None` unless the view is a single row, `other` is a column and both have the same length
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let y = Vector::new(vec![1, 10], true);
+    /// 
+    /// assert_eq!(x.row_view(1).inner(&y), Some(43));
+    /// assert_eq!(x.col_view(1).inner(&y), None);
+    /// ```
+    pub fn inner(&self, other: &Vector<T>) -> Option<T> {
+        if self.rows != 1 || !other.is_column() || self.cols != other.len() {
+            return None;
+        }
+        let mut result = T::default();
+        for (x, y) in self.iter().zip(other.iter()) {
+            result += *x * *y;
+        }
+        return Some(result);
+    }
+}
+
+impl<'a, T> Clone for MatrixView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MatrixView<'a, T> {}
+
+impl<'a, 'b, T: PartialEq> PartialEq<MatrixView<'b, T>> for MatrixView<'a, T> {
+    /// Views are equal when they have the same dimensions and elements, wherever they are stored
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 2, 1]);
+    /// 
+    /// assert_eq!(x.view(), x.view().transpose());
+    /// assert_ne!(x.row_view(0), x.col_view(0));
+    /// ```
+    fn eq(&self, other: &MatrixView<'b, T>) -> bool {
+        return self.rows == other.rows && self.cols == other.cols && self.iter().eq(other.iter());
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixView<'a, T> {
+    type Output = T;
+
+    /// Index the view with a (row, column) pair
+    /// 
+    /// # Panics
+    /// Panics if the position is out of bounds
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.get(row, col) {
+            Some(value) => return value,
+            None => panic!("Position ({}, {}) is out of bounds for a {}x{} view", row, col, self.rows, self.cols),
+        }
+    }
+}
+
+impl<'a, T: Display> Render for MatrixView<'a, T> {
+    fn cells(&self) -> Vec<Vec<String>> {
+        return self.row_iter().map(|row| row.iter().map(|x| x.to_string()).collect()).collect();
+    }
+}
+
+impl<'a, T: Display> Display for MatrixView<'a, T> {
+    /// Display the view the same way as the equivalent matrix
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(format!("{}", x.row_view(1)), "[ 3 4 ]");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return f.write_str(&Layout::default().render(self));
+    }
+}
+
+impl<'a, 'b, T: Add<Output = T> + Copy> Add<MatrixView<'b, T>> for MatrixView<'a, T> {
+    type Output = Matrix<T>;
+
+    /// Add two views into a new matrix
+    /// 
+    /// # Panics
+    /// Panics if the views have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view() + x.view().transpose(), Matrix::new(2, 2, vec![2, 5, 5, 8]));
+    /// ```
+    fn add(self, other: MatrixView<'b, T>) -> Matrix<T> {
+        if self.rows != other.rows || self.cols != other.cols {
+            panic!("Views must have the same dimensions");
+        }
+        return Matrix::new(self.rows, self.cols, self.iter().zip(other.iter()).map(|(a, b)| *a + *b).collect());
+    }
+}
+
+impl<'a, 'b, T: Sub<Output = T> + Copy> Sub<MatrixView<'b, T>> for MatrixView<'a, T> {
+    type Output = Matrix<T>;
+
+    /// Subtract two views into a new matrix
+    /// 
+    /// # Panics
+    /// Panics if the views have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view() - x.view().transpose(), Matrix::new(2, 2, vec![0, -1, 1, 0]));
+    /// ```
+    fn sub(self, other: MatrixView<'b, T>) -> Matrix<T> {
+        if self.rows != other.rows || self.cols != other.cols {
+            panic!("Views must have the same dimensions");
+        }
+        return Matrix::new(self.rows, self.cols, self.iter().zip(other.iter()).map(|(a, b)| *a - *b).collect());
+    }
+}
+
+impl<'a, 'b, T: AddAssign + Default + Mul<Output = T> + Copy> Mul<MatrixView<'b, T>> for MatrixView<'a, T> {
+    type Output = Matrix<T>;
+
+    /// Multiply two views into a new matrix
+    /// 
+    /// # Panics
+    /// Panics if the number of columns of `self` isn't the number of rows of `other`
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// // A Gram matrix without copying the transpose
+    /// assert_eq!(x.view() * x.view().transpose(), x.clone() * x.transpose());
+    /// ```
+    fn mul(self, other: MatrixView<'b, T>) -> Matrix<T> {
+        if self.cols != other.rows {
+            panic!("Can't multiply a {}x{} view by a {}x{} view", self.rows, self.cols, other.rows, other.cols);
+        }
+        let mut result = Matrix::new(self.rows, other.cols, vec![T::default(); self.rows * other.cols]);
+        for row in 0..self.rows {
+            for k in 0..self.cols {
+                let value = self[(row, k)];
+                for col in 0..other.cols {
+                    result[(row, col)] += value * other[(k, col)];
+                }
+            }
+        }
+        return result;
+    }
+}
+
+impl<'a, T: AddAssign + Default + Mul<Output = T> + Copy> Mul<Vector<T>> for MatrixView<'a, T> {
+    type Output = Vector<T>;
+
+    /// Multiply a view by a column vector
+    /// 
+    /// # Panics
+    /// Panics if the vector isn't a column, or doesn't have an element for every column of the view
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// use lemonmath::vectors::Vector;
+    /// 
+    /// let x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// assert_eq!(x.view().transpose() * Vector::new(vec![1, 1], true), Vector::new(vec![4, 6], true));
+    /// ```
+    fn mul(self, other: Vector<T>) -> Vector<T> {
+        if !other.is_column() || other.len() != self.cols {
+            panic!("Can only multiply a {}x{} view by a column vector of length {}", self.rows, self.cols, self.cols);
+        }
+        return self.row_iter().map(|row| row.inner(&other).unwrap()).collect();
+    }
+}
+
+impl<'a, T: Clone> From<MatrixView<'a, T>> for Matrix<T> {
+    /// Copy a view into a new matrix
+    fn from(view: MatrixView<'a, T>) -> Self {
+        return view.to_matrix();
+    }
+}
+
+impl<'a, T: Clone> From<MatrixView<'a, T>> for Vector<T> {
+    /// Copy a single row or column view into a vector, see [`MatrixView::to_vector`]
+    fn from(view: MatrixView<'a, T>) -> Self {
+        return view.to_vector();
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// The number of rows in the view
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.view_mut().rows(), 2);
+    /// ```
+    pub fn rows(&self) -> usize {
+        return self.rows;
+    }
+    /// The number of columns in the view
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+    /// 
+    /// assert_eq!(x.view_mut().cols(), 3);
+    /// ```
+    pub fn cols(&self) -> usize {
+        return self.cols;
+    }
+    /// Reborrow as a read-only view
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let view = x.block_view_mut(0..1, 0..2);
+    /// 
+    /// assert_eq!(view.as_view().to_matrix(), Matrix::new(1, 2, vec![1, 2]));
+    /// ```
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        return MatrixView {
+            data: self.data,
+            offset: self.offset,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        };
+    }
+    /// Get an element of the view mutably, or `None` if the position is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// *x.view_mut().get_mut(0, 1).unwrap() = 7;
+    /// 
+    /// assert_eq!(x[(0, 1)], 7);
+    /// ```
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        return Some(&mut self.data[self.offset + row * self.row_stride + col * self.col_stride]);
+    }
+    /// Mutably view a row of this view
+    /// 
+    /// # Panics
+    /// Panics if the row is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// x.view_mut().row_mut(1).fill(0);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![1, 2, 0, 0]));
+    /// ```
+    pub fn row_mut(&mut self, row: usize) -> MatrixViewMut<'_, T> {
+        let cols = self.cols;
+        return self.submatrix_mut(row..row + 1, 0..cols);
+    }
+    /// Mutably view a column of this view
+    /// 
+    /// # Panics
+    /// Panics if the column is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// x.view_mut().col_mut(1).fill(0);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![1, 0, 3, 0]));
+    /// ```
+    pub fn col_mut(&mut self, col: usize) -> MatrixViewMut<'_, T> {
+        let rows = self.rows;
+        return self.submatrix_mut(0..rows, col..col + 1);
+    }
+    /// Mutably view the block of this view covered by a range of rows and a range of columns
+    /// 
+    /// # Panics
+    /// Panics if a range is out of bounds
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// 
+    /// x.view_mut().submatrix_mut(1..3, 1..3).fill(0);
+    /// 
+    /// assert_eq!(x, Matrix::new(3, 3, vec![1, 2, 3, 4, 0, 0, 7, 0, 0]));
+    /// ```
+    pub fn submatrix_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatrixViewMut<'_, T> {
+        let (offset, rows, cols) = check_block(self.rows, self.cols, rows, cols, self.row_stride, self.col_stride);
+        return MatrixViewMut {
+            data: self.data,
+            offset: self.offset + offset,
+            rows,
+            cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        };
+    }
+    /// Call a function on every element of the view, in row order
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// x.view_mut().col_mut(0).for_each_mut(|x| *x *= 10);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![10, 2, 30, 4]));
+    /// ```
+    pub fn for_each_mut<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                f(&mut self.data[self.offset + row * self.row_stride + col * self.col_stride]);
+            }
+        }
+    }
+}
+
+impl<'a, T: Clone> MatrixViewMut<'a, T> {
+    /// Set every element of the view to a value
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// 
+    /// x.view_mut().fill(9);
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![9, 9, 9, 9]));
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        self.for_each_mut(|x| *x = value.clone());
+    }
+    /// Copy the elements of another view of the same dimensions into this one
+    /// 
+    /// # Errors
+    /// Returns [`MatrixError::DimensionMismatch`] if the views have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let y = Matrix::new(1, 2, vec![5, 6]);
+    /// 
+    /// x.view_mut().row_mut(0).assign(y.view()).unwrap();
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![5, 6, 3, 4]));
+    /// ```
+    pub fn assign(&mut self, other: MatrixView<'_, T>) -> Result<(), MatrixError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let mut values = other.iter();
+        self.for_each_mut(|x| *x = values.next().unwrap().clone());
+        return Ok(());
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixViewMut<'a, T> {
+    type Output = T;
+
+    /// Index the view with a (row, column) pair
+    /// 
+    /// # Panics
+    /// Panics if the position is out of bounds
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        if row >= self.rows || col >= self.cols {
+            panic!("Position ({}, {}) is out of bounds for a {}x{} view", row, col, self.rows, self.cols);
+        }
+        return &self.data[self.offset + row * self.row_stride + col * self.col_stride];
+    }
+}
+
+impl<'a, T> IndexMut<(usize, usize)> for MatrixViewMut<'a, T> {
+    /// Mutably index the view with a (row, column) pair
+    /// 
+    /// # Panics
+    /// Panics if the position is out of bounds
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(row, col) {
+            Some(value) => return value,
+            None => panic!("Position ({}, {}) is out of bounds for a {}x{} view", row, col, rows, cols),
+        }
+    }
+}
+
+impl<'a, 'b, T: AddAssign + Copy> AddAssign<MatrixView<'b, T>> for MatrixViewMut<'a, T> {
+    /// Add another view to this one in place
+    /// 
+    /// # Panics
+    /// Panics if the views have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let y = Matrix::new(1, 2, vec![10, 20]);
+    /// 
+    /// let mut row = x.block_view_mut(1..2, 0..2);
+    /// row += y.view();
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![1, 2, 13, 24]));
+    /// ```
+    fn add_assign(&mut self, other: MatrixView<'b, T>) {
+        if self.rows != other.rows || self.cols != other.cols {
+            panic!("Views must have the same dimensions");
+        }
+        let mut values = other.iter();
+        self.for_each_mut(|x| *x += *values.next().unwrap());
+    }
+}
+
+impl<'a, 'b, T: SubAssign + Copy> SubAssign<MatrixView<'b, T>> for MatrixViewMut<'a, T> {
+    /// Subtract another view from this one in place
+    /// 
+    /// # Panics
+    /// Panics if the views have different dimensions
+    /// 
+    /// # Examples
+    /// ```
+    /// use lemonmath::matrices::Matrix;
+    /// 
+    /// let mut x = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+    /// let y = Matrix::new(2, 1, vec![1, 1]);
+    /// 
+    /// let mut col = x.block_view_mut(0..2, 1..2);
+    /// col -= y.view();
+    /// 
+    /// assert_eq!(x, Matrix::new(2, 2, vec![1, 1, 3, 3]));
+    /// ```
+    fn sub_assign(&mut self, other: MatrixView<'b, T>) {
+        if self.rows != other.rows || self.cols != other.cols {
+            panic!("Views must have the same dimensions");
+        }
+        let mut values = other.iter();
+        self.for_each_mut(|x| *x -= *values.next().unwrap());
+    }
+}
+
+// Check that a block fits and find its offset, rows and columns
+fn check_block(rows: usize, cols: usize, row_range: Range<usize>, col_range: Range<usize>, row_stride: usize, col_stride: usize) -> (usize, usize, usize) {
+    if row_range.start > row_range.end || col_range.start > col_range.end || row_range.end > rows || col_range.end > cols {
+        panic!("The block {:?}x{:?} is out of bounds for a {}x{} view", row_range, col_range, rows, cols);
+    }
+    return (row_range.start * row_stride + col_range.start * col_stride, row_range.len(), col_range.len());
+}